@@ -0,0 +1,436 @@
+//! Derive macros for [bootstrap-dashboard](https://crates.io/crates/bootstrap-dashboard).
+//!
+//! Use them through the `derive` feature of `bootstrap-dashboard`, rather
+//! than depending on this crate directly.
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Field, Fields, GenericArgument,
+    Ident, LitStr, PathArguments, Type,
+};
+
+/// Implements `bootstrap_dashboard::table::DashboardTable`, generating one
+/// column per field.
+///
+/// See the documentation of the trait for the supported `#[table(...)]` attributes.
+///
+/// # Errors
+///
+/// Invalid attributes are reported at compile time, such as unknown ones:
+///
+/// ```compile_fail
+/// #[derive(bootstrap_dashboard::table::DashboardTable)]
+/// struct User {
+///     #[table(sortable, colour = "red")]
+///     name: String,
+/// }
+/// ```
+///
+/// Alignments other than `"left"`, `"center"` and `"right"`:
+///
+/// ```compile_fail
+/// #[derive(bootstrap_dashboard::table::DashboardTable)]
+/// struct User {
+///     #[table(align = "justify")]
+///     name: String,
+/// }
+/// ```
+///
+/// Links referring to fields which do not exist:
+///
+/// ```compile_fail
+/// #[derive(bootstrap_dashboard::table::DashboardTable)]
+/// struct User {
+///     #[table(link = "/users/{user_id}")]
+///     name: String,
+/// }
+/// ```
+///
+/// Links on `markup` fields:
+///
+/// ```compile_fail
+/// #[derive(bootstrap_dashboard::table::DashboardTable)]
+/// struct User {
+///     id: u32,
+///     #[table(markup, link = "/users/{id}")]
+///     name: String,
+/// }
+/// ```
+///
+/// And anything but structs with named fields:
+///
+/// ```compile_fail
+/// #[derive(bootstrap_dashboard::table::DashboardTable)]
+/// struct User(u32, String);
+/// ```
+#[proc_macro_derive(DashboardTable, attributes(table))]
+pub fn derive_dashboard_table(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    dashboard_table(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn dashboard_table(input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.span(),
+            "DashboardTable can only be derived for structs",
+        ));
+    };
+
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            data.fields.span(),
+            "DashboardTable can only be derived for structs with named fields",
+        ));
+    };
+
+    let names: Vec<&Ident> = fields
+        .named
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .collect();
+
+    let mut columns = Vec::new();
+    for field in &fields.named {
+        let options = ColumnOptions::parse(field)?;
+        if !options.skip {
+            columns.push(options.column(field, &names)?);
+        }
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::bootstrap_dashboard::table::DashboardTable for #ident #type_generics #where_clause {
+            fn columns() -> ::std::vec::Vec<::bootstrap_dashboard::table::TableColumn<Self>> {
+                ::std::vec![#(#columns),*]
+            }
+        }
+    })
+}
+
+#[derive(Default)]
+struct ColumnOptions {
+    label: Option<LitStr>,
+    format: Option<LitStr>,
+    link: Option<LitStr>,
+    align: Option<Ident>,
+    markup: bool,
+    sortable: bool,
+    hidden: bool,
+    skip: bool,
+}
+
+impl ColumnOptions {
+    fn parse(field: &Field) -> syn::Result<Self> {
+        let mut options = ColumnOptions::default();
+
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("table"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("label") {
+                    options.label = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("format") {
+                    options.format = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("link") {
+                    options.link = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("align") {
+                    let align: LitStr = meta.value()?.parse()?;
+                    let variant = match align.value().as_str() {
+                        "left" => "Left",
+                        "center" => "Center",
+                        "right" => "Right",
+                        _ => {
+                            return Err(Error::new(
+                                align.span(),
+                                "expected \"left\", \"center\" or \"right\"",
+                            ))
+                        }
+                    };
+                    options.align = Some(Ident::new(variant, align.span()));
+                } else if meta.path.is_ident("markup") {
+                    options.markup = true;
+                } else if meta.path.is_ident("sortable") {
+                    options.sortable = true;
+                } else if meta.path.is_ident("hidden") {
+                    options.hidden = true;
+                } else if meta.path.is_ident("skip") {
+                    options.skip = true;
+                } else {
+                    return Err(meta.error("unsupported table attribute"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        if options.markup && options.link.is_some() {
+            return Err(Error::new(
+                field.span(),
+                "`markup` and `link` cannot be combined",
+            ));
+        }
+
+        Ok(options)
+    }
+
+    fn column(&self, field: &Field, names: &[&Ident]) -> syn::Result<TokenStream> {
+        let ident = field.ident.as_ref().expect("named field");
+        let key = ident.to_string();
+        let label = match &self.label {
+            Some(label) => label.to_token_stream(),
+            None => sentence_case(&key).to_token_stream(),
+        };
+
+        let value = match (option_type(&field.ty), &self.format) {
+            (false, Some(format)) => quote!(::std::format!(#format, row.#ident)),
+            (false, None) => quote!(::std::string::ToString::to_string(&row.#ident)),
+            (true, Some(format)) => quote! {
+                match &row.#ident {
+                    ::std::option::Option::Some(value) => ::std::format!(#format, value),
+                    ::std::option::Option::None => ::std::string::String::new(),
+                }
+            },
+            (true, None) => quote! {
+                row.#ident
+                    .as_ref()
+                    .map(::std::string::ToString::to_string)
+                    .unwrap_or_default()
+            },
+        };
+
+        let table = quote!(::bootstrap_dashboard::table);
+        let mut column = match &self.link {
+            Some(link) => {
+                let (format, args) = link_format(link, names)?;
+                quote! {
+                    #table::TableColumn::link(
+                        #key,
+                        #label,
+                        |row: &Self| ::std::format!(#format, #(#args),*),
+                        |row: &Self| #value,
+                    )
+                }
+            }
+            None if self.markup => {
+                quote!(#table::TableColumn::markup(#key, #label, |row: &Self| #value))
+            }
+            None => quote!(#table::TableColumn::new(#key, #label, |row: &Self| #value)),
+        };
+
+        if self.link.is_none() && !self.markup && self.format.is_none() && scalar_type(&field.ty) {
+            column.extend(quote!(.with_export(|row: &Self| row.#ident)));
+        }
+
+        if let Some(align) = &self.align {
+            column.extend(quote!(.with_align(#table::Alignment::#align)));
+        }
+
+        if self.sortable {
+            column.extend(quote!(.sortable()));
+        }
+
+        if self.hidden {
+            column.extend(quote!(.hidden()));
+        }
+
+        Ok(column)
+    }
+}
+
+/// Turn a `link` such as `"/users/{id}"` into a format string with
+/// positional arguments, each of which percent-encodes the referenced field.
+fn link_format(link: &LitStr, names: &[&Ident]) -> syn::Result<(LitStr, Vec<TokenStream>)> {
+    let value = link.value();
+    let mut chars = value.chars().peekable();
+    let mut format = String::new();
+    let mut args = Vec::new();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                format.push_str("{{");
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                format.push_str("}}");
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => placeholder.push(c),
+                        None => return Err(Error::new(link.span(), "unclosed `{` in link")),
+                    }
+                }
+
+                let (name, spec) = placeholder
+                    .split_once(':')
+                    .unwrap_or((placeholder.as_str(), ""));
+                let name = name.trim();
+                let Some(field) = names
+                    .iter()
+                    .find(|field| field.to_string().trim_start_matches("r#") == name)
+                else {
+                    return Err(Error::new(
+                        link.span(),
+                        format!("link refers to unknown field `{name}`"),
+                    ));
+                };
+
+                let arg = if spec.is_empty() {
+                    quote!(::std::string::ToString::to_string(&row.#field))
+                } else {
+                    let spec = LitStr::new(&format!("{{:{spec}}}"), link.span());
+                    quote!(::std::format!(#spec, row.#field))
+                };
+
+                args.push(quote!(::bootstrap_dashboard::route::encode_component(&#arg)));
+                format.push_str("{}");
+            }
+            '}' => return Err(Error::new(link.span(), "unmatched `}` in link")),
+            c => format.push(c),
+        }
+    }
+
+    Ok((LitStr::new(&format, link.span()), args))
+}
+
+/// Whether the type is syntactically an `Option<T>`.
+fn option_type(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+
+    path.path.segments.last().is_some_and(|segment| {
+        segment.ident == "Option"
+            && matches!(
+                &segment.arguments,
+                PathArguments::AngleBracketed(args)
+                    if matches!(args.args.first(), Some(GenericArgument::Type(_)))
+            )
+    })
+}
+
+/// Whether the type is a number or `bool`, or an `Option` of one, which are
+/// exported as-is to keep them typed in JSON exports.
+fn scalar_type(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    let Some(segment) = path.path.segments.last() else {
+        return false;
+    };
+
+    match &segment.arguments {
+        PathArguments::None => matches!(
+            segment.ident.to_string().as_str(),
+            "bool"
+                | "i8"
+                | "i16"
+                | "i32"
+                | "i64"
+                | "i128"
+                | "isize"
+                | "u8"
+                | "u16"
+                | "u32"
+                | "u64"
+                | "u128"
+                | "usize"
+                | "f32"
+                | "f64"
+        ),
+        PathArguments::AngleBracketed(args) if segment.ident == "Option" => {
+            matches!(args.args.first(), Some(GenericArgument::Type(ty)) if scalar_type(ty))
+        }
+        _ => false,
+    }
+}
+
+/// `created_at` becomes `Created at`.
+fn sentence_case(name: &str) -> String {
+    let name = name.trim_start_matches("r#").replace('_', " ");
+    let mut chars = name.trim().chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::dashboard_table;
+
+    fn error(input: syn::DeriveInput) -> String {
+        dashboard_table(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn invalid_attributes() {
+        assert_eq!(
+            error(parse_quote! {
+                struct User {
+                    #[table(sortable, colour = "red")]
+                    name: String,
+                }
+            }),
+            "unsupported table attribute"
+        );
+        assert_eq!(
+            error(parse_quote! {
+                struct User {
+                    #[table(align = "justify")]
+                    name: String,
+                }
+            }),
+            "expected \"left\", \"center\" or \"right\""
+        );
+        assert_eq!(
+            error(parse_quote! {
+                struct User {
+                    #[table(link = "/users/{user_id}")]
+                    name: String,
+                }
+            }),
+            "link refers to unknown field `user_id`"
+        );
+        assert_eq!(
+            error(parse_quote! {
+                struct User {
+                    #[table(link = "/users/{name")]
+                    name: String,
+                }
+            }),
+            "unclosed `{` in link"
+        );
+        assert_eq!(
+            error(parse_quote! {
+                struct User {
+                    id: u32,
+                    #[table(markup, link = "/users/{id}")]
+                    name: String,
+                }
+            }),
+            "`markup` and `link` cannot be combined"
+        );
+        assert_eq!(
+            error(parse_quote!(
+                struct User(u32, String);
+            )),
+            "DashboardTable can only be derived for structs with named fields"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use bootstrap_dashboard::table::{Alignment, DashboardTable, ExportFormat};
+
+#[derive(DashboardTable)]
+struct User {
+    #[table(label = "#", link = "/teams/{team}/users/{id:03}", sortable)]
+    id: u32,
+    #[table(sortable)]
+    display_name: String,
+    #[table(format = "{:.1}%", align = "right")]
+    quota_used: f32,
+    #[table(hidden)]
+    email: Option<String>,
+    #[table(markup)]
+    badge: String,
+    #[table(skip)]
+    team: String,
+}
+
+fn user() -> User {
+    User {
+        id: 7,
+        display_name: "<Jane>".into(),
+        quota_used: 12.34,
+        email: None,
+        badge: r#"<span class="badge badge-success">Active</span>"#.into(),
+        team: "R&D/Ops".into(),
+    }
+}
+
+#[test]
+fn generated_columns() {
+    let columns = User::columns();
+
+    let keys: Vec<_> = columns.iter().map(|column| column.key.as_ref()).collect();
+    assert_eq!(keys, ["id", "display_name", "quota_used", "email", "badge"]);
+
+    let headers: Vec<_> = columns
+        .iter()
+        .map(|column| column.header.as_ref())
+        .collect();
+    assert_eq!(
+        headers,
+        ["#", "Display name", "Quota used", "Email", "Badge"]
+    );
+
+    assert!(columns[0].sortable && columns[1].sortable && !columns[2].sortable);
+    assert_eq!(columns[2].align, Alignment::Right);
+    assert!(columns[3].hidden);
+    assert!(!columns[4].exported());
+}
+
+#[test]
+fn render_table() {
+    let table = User::table("users", "/users")
+        .with_rows([user()])
+        .to_string();
+
+    assert!(table.contains(r#"<a href="/teams/R%26D%2FOps/users/007">7</a>"#));
+    assert!(table.contains("&lt;Jane&gt;"));
+    assert!(table.contains("12.3%"));
+    assert!(table.contains(r#"<span class="badge badge-success">Active</span>"#));
+    assert!(!table.contains("Email"));
+    assert!(!table.contains("Team"));
+}
+
+#[test]
+fn render_details() {
+    let details = user().details().to_string();
+
+    assert!(details.contains(r#"<dt class="col-sm-4">Email</dt>"#));
+    assert!(details.contains(r#"<dd class="col-sm-8"></dd>"#));
+    assert!(details.contains(r#"<dd class="col-sm-8">12.3%</dd>"#));
+    assert!(!details.contains("Team"));
+}
+
+#[derive(DashboardTable)]
+struct Quota {
+    name: String,
+    used: f64,
+    limit: Option<u64>,
+    unlimited: bool,
+    #[table(format = "{}%")]
+    percent: u8,
+}
+
+#[test]
+fn export_typed_values() {
+    let quota = Quota {
+        name: "=1+1".into(),
+        used: 1.5,
+        limit: None,
+        unlimited: true,
+        percent: 50,
+    };
+
+    let json: String = Quota::table("quotas", "/quotas")
+        .with_rows([quota])
+        .into_export(ExportFormat::Json)
+        .collect();
+
+    assert_eq!(
+        json,
+        "[\n  {\"name\": \"=1+1\", \"used\": 1.5, \"limit\": null, \"unlimited\": true, \"percent\": \"50%\"}\n]\n"
+    );
+}
@@ -54,7 +54,7 @@ async fn others(OriginalUri(uri): OriginalUri) -> impl IntoResponse {
             .with_content(
                 Dashboard::default()
                     .with_sidebar(sidebar_builder().await.with_active_from_path(uri.path()))
-                    .with_page_header("A link page")
+                    .with_sidebar_breadcrumbs()
                     .replace_content("This is a link page"),
             )
             .to_string(),
@@ -0,0 +1,103 @@
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+use axum::{
+    response::{Html, IntoResponse},
+    routing::get,
+    Router,
+};
+use bootstrap_dashboard::{
+    card::Card,
+    chart::{Chart, ChartData, Dataset, ValueFormat},
+    grid, Color, Dashboard, Page,
+};
+use tokio::net::TcpListener;
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+static REQUESTS: AtomicU32 = AtomicU32::new(0);
+
+#[tokio::main]
+async fn main() {
+    let app = Router::new()
+        .route("/", get(index))
+        .route("/earnings.json", get(earnings_data))
+        .merge(bootstrap_dashboard::files::serve_at(
+            "/static-path/nested/*path",
+        ));
+
+    println!("Example running at http://localhost:3000");
+
+    let listener = TcpListener::bind("0.0.0.0:3000").await.unwrap();
+
+    axum::serve(listener, app.into_make_service())
+        .await
+        .unwrap();
+}
+
+fn earnings() -> Chart {
+    let offset = REQUESTS.fetch_add(1, Ordering::Relaxed);
+
+    Chart::area()
+        .with_id("earnings")
+        .with_labels(MONTHS)
+        .with_dataset(Dataset::new(
+            "Earnings",
+            (0..12).map(|month| f64::from((month * 7919 + offset * 1543) % 40000)),
+        ))
+        .with_format(ValueFormat::new().with_prefix("$").with_decimals(0))
+        .with_refresh("/earnings.json", Duration::from_secs(5))
+}
+
+/// Polled by the earnings chart, returning new values every time.
+async fn earnings_data() -> ChartData {
+    earnings().data()
+}
+
+async fn index() -> impl IntoResponse {
+    let revenue = Chart::doughnut()
+        .with_labels(["Direct", "Social", "Referral"])
+        .with_dataset(Dataset::new("Sources", [55, 30, 15]))
+        .with_format(ValueFormat::new().with_suffix("%"))
+        .with_legend();
+
+    let signups = Chart::bar()
+        .with_labels(["January", "February", "March", "April", "May", "June"])
+        .with_dataset(
+            Dataset::new("Signups", [4215, 5312, 6251, 7841, 9821, 14984]).with_color(Color::Info),
+        );
+
+    let load = Chart::line()
+        .with_labels(["00:00", "04:00", "08:00", "12:00", "16:00", "20:00"])
+        .with_dataset(Dataset::new(
+            "eu-west",
+            [22.5, 18.0, 64.2, 80.1, 72.3, 40.8],
+        ))
+        .with_dataset(Dataset::new(
+            "us-east",
+            [35.0, 48.2, 30.4, 52.9, 77.0, 61.6],
+        ))
+        .with_format(ValueFormat::new().with_suffix("%").with_decimals(1))
+        .with_legend();
+
+    let content = grid! {
+        row {
+            col(xl = 8) => Card::new(earnings()).with_header("Earnings Overview"),
+            col(xl = 4) => Card::new(revenue).with_header("Revenue Sources"),
+        }
+        row {
+            col(lg = 6) => Card::new(signups).with_header("Signups"),
+            col(lg = 6) => Card::new(load).with_header("Load"),
+        }
+    };
+
+    Html(
+        Page::new("Charts", "/static-path/nested")
+            .with_content(Dashboard::default().replace_content(content))
+            .to_string(),
+    )
+}
@@ -1,3 +1,8 @@
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
 use axum::{
     body::Body,
     http::{header, HeaderValue, Response, StatusCode},
@@ -6,10 +11,14 @@ use axum::{
     Router,
 };
 use bootstrap_dashboard::{
-    card::{Card, CardButton, ContextGroup},
-    grid::{Breakpoint, Column, Row},
-    icons, Alert, AlertList, Alerts, Color, Dashboard, Group, IconLink, LinkAction, NavItem, Page,
-    PlainLink, Sidebar, SubGroup, UserInfo,
+    card::{Card, CardButton, CardState, ContextGroup, ContextItem, StatCard, Trend},
+    grid,
+    grid::{Breakpoint, Column, Layout, Row, Span},
+    htmx::{HxSwap, TriggerEvent},
+    icons,
+    modal::{Modal, ModalAction},
+    Alert, AlertList, Alerts, Color, Dashboard, Group, IconLink, LinkAction, NavItem, Page,
+    PageHeader, PlainLink, Sidebar, SidebarCard, SidebarState, SubGroup, Text, UserInfo,
 };
 use tokio::net::TcpListener;
 
@@ -19,6 +28,8 @@ async fn main() {
     let app = Router::new()
         .route("/", get(index))
         .route("/configuration", get(configuration))
+        .route("/stats/visitors", get(visitors))
+        .route("/reports/slow", get(slow_report))
         .route("/img/undraw_profile.svg", get(serve_profile_image))
         .merge(bootstrap_dashboard::files::serve_at(
             "/static-path/nested/*path",
@@ -46,20 +57,70 @@ async fn serve_profile_image() -> impl IntoResponse {
         .unwrap()
 }
 
-async fn index() -> impl IntoResponse {
+async fn index(sidebar_state: SidebarState) -> impl IntoResponse {
     Html(
         Page::new("Dashboard", "/static-path/nested")
+            .with_modal(logout_modal())
             .with_content(
-                dashboard_builder("Dashboard")
+                dashboard_builder("Dashboard", &sidebar_state)
                     .await
-                    .with_page_header("Dashboard")
-                    .replace_content("This is the front page!"),
+                    .with_page_header(
+                        PageHeader::new("Dashboard")
+                            .with_subtitle("Overview of everything going on")
+                            .with_action(
+                                CardButton::new("Generate Report")
+                                    .with_icon(icons::fa::DOWNLOAD)
+                                    .with_action(LinkAction::to("/configuration")),
+                            )
+                            .in_content(),
+                    )
+                    .replace_content(stats()),
             )
             .to_string(),
     )
 }
 
-async fn configuration() -> impl IntoResponse {
+fn stats() -> Layout {
+    grid! {
+        row {
+            col(xl = 3, md = 6) => StatCard::new(
+                "Earnings (Monthly)",
+                "$40,000",
+                Color::Primary,
+                icons::fa::CALENDAR,
+            )
+            .with_trend(Trend::up("12% since last month")),
+            col(xl = 3, md = 6) => StatCard::new("Visitors", "0", Color::Success, icons::fa::USERS)
+                .with_hx(
+                    HxSwap::get("/stats/visitors")
+                        .with_trigger(TriggerEvent::Every(Duration::from_secs(2))),
+                ),
+            col(xl = 3, md = 6) => StatCard::new("Tasks", "50%", Color::Info, icons::fa::CLIPBOARD_LIST)
+                .with_progress(50),
+            col(xl = 3, md = 6) => StatCard::new(
+                "Error Rate",
+                "0.4%",
+                Color::Warning,
+                icons::fa::EXCLAMATION_TRIANGLE,
+            )
+            .with_trend(Trend::down("0.1%").inverted()),
+        }
+    }
+}
+
+async fn visitors() -> String {
+    static VISITORS: AtomicU64 = AtomicU64::new(0);
+
+    (VISITORS.fetch_add(1, Ordering::Relaxed) + 1).to_string()
+}
+
+async fn slow_report() -> String {
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    format!("Report generated after {} visitors.", visitors().await)
+}
+
+async fn configuration(sidebar_state: SidebarState, card_state: CardState) -> impl IntoResponse {
     let row1 = Row::new()
         .with_column(
             Card::new("Hello world")
@@ -73,11 +134,10 @@ async fn configuration() -> impl IntoResponse {
                     CardButton::new("Link")
                         .with_color(Color::Danger)
                         .with_action(LinkAction::to("https://example.com")),
-                )
-                .to_string(),
+                ),
         )
         .with_column(
-            Column::new(Card::new("Hello world").to_string()).with_size(Breakpoint::ExtraLarge, 2),
+            Column::new(Card::new("Hello world")).with_size(Breakpoint::ExtraLarge, Span::new(2)),
         )
         .with_column(
             Column::new(
@@ -85,11 +145,24 @@ async fn configuration() -> impl IntoResponse {
                     .with_header("Large Card")
                     .with_context_group(
                         ContextGroup::new("Context Label")
-                            .with_link("Link", LinkAction::to("https://example.com")),
+                            .with_link("Link", LinkAction::to("https://example.com"))
+                            .with_item(
+                                ContextItem::new("Settings", LinkAction::to("/configuration"))
+                                    .with_icon(icons::fa::COGS),
+                            )
+                            .with_item(
+                                ContextItem::new("Archive", LinkAction::to("/archive")).disabled(),
+                            ),
                     )
-                    .to_string(),
+                    .with_context_group(
+                        ContextGroup::default().with_item(
+                            ContextItem::new("Log out", LinkAction::modal(&logout_modal()))
+                                .with_icon(icons::fa::SIGN_OUT_ALT)
+                                .with_color(Color::Danger),
+                        ),
+                    ),
             )
-            .with_size(Breakpoint::ExtraLarge, 7),
+            .with_size(Breakpoint::ExtraLarge, Span::new(7)),
         );
 
     let row2 = Row::new()
@@ -109,19 +182,33 @@ async fn configuration() -> impl IntoResponse {
                 .with_context_group(
                     ContextGroup::default()
                         .with_link("Link", LinkAction::to("https://example.com")),
-                )
-                .to_string(),
+                ),
         )
+        .with_column(Card::new("Combined buttons and context group").with_header("Plain"))
         .with_column(
-            Card::new("Combined buttons and context group")
-                .with_header("Plain")
-                .to_string(),
+            Card::new("Click the header to collapse this card, it will stay collapsed on reload.")
+                .with_header("Collapsible")
+                .with_header_color(Color::Info)
+                .with_footer(Text::new("Last updated just now"))
+                .collapsible("collapsibleExample")
+                .with_state(&card_state),
+        )
+        .with_column(
+            Card::lazy("/reports/slow")
+                .with_header("Lazy")
+                .with_refresh_button(),
+        )
+        .with_column(
+            Card::new("Cards can show an image at the top.")
+                .with_image("/img/undraw_profile.svg", "Profile")
+                .without_shadow(),
         );
 
     Html(
         Page::new("My First Dashbaord", "/static-path/nested")
+            .with_modal(logout_modal())
             .with_content(
-                dashboard_builder("Configuration")
+                dashboard_builder("Configuration", &sidebar_state)
                     .await
                     .with_page_header("Configuration")
                     .replace_content(format!("{row1}{row2}")),
@@ -130,7 +217,19 @@ async fn configuration() -> impl IntoResponse {
     )
 }
 
-async fn dashboard_builder(active_label: &str) -> Dashboard {
+fn logout_modal() -> Modal {
+    Modal::new(
+        "logoutModal",
+        "Ready to Leave?",
+        Text::new("Select \"Logout\" below if you are ready to end your current session."),
+        ModalAction::Get("/".into()),
+    )
+    .with_confirm("Logout")
+    .with_confirm_color(Color::Danger)
+    .centered()
+}
+
+async fn dashboard_builder(active_label: &str, sidebar_state: &SidebarState) -> Dashboard {
     let sidebar = Sidebar::new("Dashboard", icons::fa::LAUGH_SQUINT)
         .with_group(
             Group::unlabeled()
@@ -163,23 +262,38 @@ async fn dashboard_builder(active_label: &str) -> Dashboard {
                 ))
                 .with_item(PlainLink::new("Plain Link", LinkAction::to("/"))),
         )
-        .with_active_label(active_label);
+        .with_card(
+            SidebarCard::new("Configure the sidebar entirely from a TOML file!")
+                .with_image("/img/undraw_profile.svg")
+                .with_button(
+                    CardButton::new("Learn more")
+                        .with_color(Color::Success)
+                        .with_action(LinkAction::to("/configuration")),
+                ),
+        )
+        .with_footer(concat!("v", env!("CARGO_PKG_VERSION")))
+        .with_active_label(active_label)
+        .with_state(sidebar_state);
 
     let userinfo = UserInfo {
         username: "John Smith".into(),
         image: "/img/undraw_profile.svg".into(),
         groups: vec![
             vec![
-                IconLink::new("Profile", icons::fa::USER, LinkAction::to("/")).into(),
-                IconLink::new("Settings", icons::fa::COGS, LinkAction::to("/")).into(),
-                IconLink::new("Activity Log", icons::fa::LIST, LinkAction::to("/")).into(),
+                IconLink::new("Profile", icons::fa::USER, LinkAction::to("/")),
+                IconLink::new("Settings", icons::fa::COGS, LinkAction::to("/")),
+                IconLink::new("Activity Log", icons::fa::LIST, LinkAction::to("/")),
+                IconLink::new(
+                    "Documentation",
+                    icons::fa::BOOK,
+                    LinkAction::external("https://docs.rs/bootstrap-dashboard"),
+                ),
             ],
             vec![IconLink::new(
                 "Logout",
                 icons::fa::SIGN_OUT_ALT,
                 LinkAction::modal_name("logoutModal"),
-            )
-            .into()],
+            )],
         ],
     };
 
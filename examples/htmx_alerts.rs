@@ -65,7 +65,7 @@ async fn index(state: State<Arc<RwLock<Vec<Alert>>>>) -> impl IntoResponse {
         .with_active_label("Dashboard");
 
     let alerts = Alerts {
-        alerts: alerts(state).await.into(),
+        alerts: alerts(state).await,
         show_all_url: Some("/notifications".into()),
     };
 
@@ -85,7 +85,6 @@ async fn index(state: State<Arc<RwLock<Vec<Alert>>>>) -> impl IntoResponse {
 async fn alerts(State(alerts): State<Arc<RwLock<Vec<Alert>>>>) -> Dynamic<AlertList> {
     let alerts = alerts.read().await.iter().cloned().collect();
 
-    AlertList(alerts).with_hx(
-        HxSwap::get(format!("/alerts")).with_trigger(TriggerEvent::Every(Duration::from_secs(1))),
-    )
+    AlertList(alerts)
+        .with_hx(HxSwap::get("/alerts").with_trigger(TriggerEvent::Every(Duration::from_secs(1))))
 }
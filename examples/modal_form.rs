@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::State,
+    middleware,
+    response::{Html, IntoResponse, Response},
+    routing::{get, post},
+    Form as FormData, Router,
+};
+use bootstrap_dashboard::{
+    card::{Card, CardButton},
+    csrf,
+    form::{Field, Form, Submitted},
+    htmx::{EventModifier, HxSwap, IntoDynamic, TriggerEvent},
+    modal::{Modal, ModalAction},
+    Dashboard, LinkAction, Page, Text,
+};
+use serde::Deserialize;
+use tokio::{net::TcpListener, sync::RwLock};
+
+type Users = Arc<RwLock<Vec<String>>>;
+
+#[derive(Deserialize)]
+struct NewUser {
+    name: String,
+    email: String,
+}
+
+#[tokio::main]
+async fn main() {
+    let app = Router::new()
+        .route("/", get(index))
+        .route("/users", get(users))
+        .route("/users", post(create_user))
+        .route("/help", get(help))
+        .merge(bootstrap_dashboard::files::serve_at(
+            "/static-path/nested/*path",
+        ))
+        .layer(middleware::from_fn(csrf::protect))
+        .with_state(Users::default());
+
+    println!("Example running at http://localhost:3000");
+
+    let listener = TcpListener::bind("0.0.0.0:3000").await.unwrap();
+
+    axum::serve(listener, app.into_make_service())
+        .await
+        .unwrap();
+}
+
+fn user_form() -> Form {
+    Form::hx_post("newUserForm", "/users")
+        .with_field(Field::text("name", "Name").required())
+        .with_field(
+            Field::email("email", "Email")
+                .with_placeholder("jane@example.com")
+                .with_prepend("@"),
+        )
+        .with_field(
+            Field::select("role", "Role")
+                .with_option("user", "User")
+                .with_option("admin", "Administrator")
+                .with_value("user"),
+        )
+        .with_field(
+            Field::switch("notify", "Send welcome email")
+                .with_help("The email contains a link to set a password."),
+        )
+}
+
+async fn index(State(users): State<Users>) -> impl IntoResponse {
+    let card = Card::new(
+        users_list(&users)
+            .await
+            .with_hx(
+                HxSwap::get("/users").with_trigger(
+                    TriggerEvent::Custom("usersChanged")
+                        .with_modifier(EventModifier::From("body".into())),
+                ),
+            ),
+    )
+    .with_header("Users")
+    .with_button(
+        CardButton::new("Help")
+            .with_outline()
+            .with_action(LinkAction::lazy_modal("/help")),
+    )
+    .with_button(CardButton::new("Add user").with_action(LinkAction::modal_name("newUserModal")));
+
+    Html(
+        Page::new("Dashboard", "/static-path/nested")
+            .with_modal(Modal::form("newUserModal", "New user", user_form()).with_confirm("Create"))
+            .with_content(Dashboard::default().replace_content(card))
+            .to_string(),
+    )
+}
+
+async fn help() -> impl IntoResponse {
+    // Simulate an expensive lookup, showing off the loading spinner.
+    tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+
+    Html(
+        Modal::new(
+            "helpModal",
+            "Help",
+            Text::new("Users added through the modal form show up in the card immediately."),
+            ModalAction::Get("/".into()),
+        )
+        .with_confirm("Got it")
+        .to_string(),
+    )
+}
+
+async fn users(State(users): State<Users>) -> impl IntoResponse {
+    Html(users_list(&users).await.to_string())
+}
+
+async fn users_list(users: &Users) -> Text {
+    Text::new(users.read().await.join(", "))
+}
+
+async fn create_user(State(users): State<Users>, FormData(user): FormData<NewUser>) -> Response {
+    let mut form = user_form()
+        .without_submit()
+        .with_field_value("name", user.name.clone())
+        .with_field_value("email", user.email.clone());
+
+    if user.name.trim().is_empty() {
+        form = form.with_field_feedback("name", "Please enter a name.");
+    }
+
+    if !user.email.contains('@') {
+        form = form.with_field_feedback("email", "Please enter a valid email address.");
+    }
+
+    if form.has_feedback() {
+        return Html(form.to_string()).into_response();
+    }
+
+    users.write().await.push(user.name);
+    Submitted::new()
+        .with_trigger("usersChanged")
+        .into_response()
+}
@@ -81,6 +81,10 @@ fn dashboard_builder(active_label: &str) -> Dashboard {
                     LinkAction::to("/"),
                 )),
         ],
+        toggled: false,
+        card: None,
+        bottom: None,
+        footer: None,
     }
     .with_active_label(active_label);
 
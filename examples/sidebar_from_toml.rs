@@ -0,0 +1,53 @@
+use axum::{
+    extract::OriginalUri,
+    response::{Html, IntoResponse},
+    routing::get,
+    Router,
+};
+use bootstrap_dashboard::{Dashboard, Page, Sidebar};
+use tokio::net::TcpListener;
+
+const SIDEBAR: &str = include_str!("sidebar.toml");
+
+#[tokio::main]
+async fn main() {
+    // Parse once up front, so configuration errors surface immediately.
+    if let Err(err) = toml::from_str::<Sidebar>(SIDEBAR) {
+        panic!("invalid sidebar configuration: {err}");
+    }
+
+    // build our application with a route
+    let app = Router::new()
+        .route("/", get(index))
+        .route("/configuration", get(index))
+        .route("/lorem", get(index))
+        .route("/ipsum", get(index))
+        .merge(bootstrap_dashboard::files::serve_at(
+            "/static-path/nested/*path",
+        ));
+
+    println!("Example running at http://localhost:3000");
+
+    let listener = TcpListener::bind("0.0.0.0:3000").await.unwrap();
+
+    axum::serve(listener, app.into_make_service())
+        .await
+        .unwrap();
+}
+
+async fn index(OriginalUri(uri): OriginalUri) -> impl IntoResponse {
+    let sidebar = toml::from_str::<Sidebar>(SIDEBAR)
+        .unwrap()
+        .with_active_from_path(uri.path());
+
+    Html(
+        Page::new("Dashboard", "/static-path/nested")
+            .with_content(
+                Dashboard::default()
+                    .with_sidebar(sidebar)
+                    .with_page_header("Sidebar from TOML")
+                    .replace_content(uri.path().to_string()),
+            )
+            .to_string(),
+    )
+}
@@ -0,0 +1,103 @@
+use axum::{
+    response::{Html, IntoResponse},
+    routing::get,
+    Router,
+};
+use bootstrap_dashboard::{
+    card::{Card, StatCard},
+    chart::{Dataset, ValueFormat},
+    grid, icons,
+    svg::{Sparkline, SvgChart},
+    Color, Dashboard, Page,
+};
+use tokio::net::TcpListener;
+
+#[tokio::main]
+async fn main() {
+    let app = Router::new()
+        .route("/", get(index))
+        .merge(bootstrap_dashboard::files::serve_at(
+            "/static-path/nested/*path",
+        ));
+
+    println!("Example running at http://localhost:3000");
+
+    let listener = TcpListener::bind("0.0.0.0:3000").await.unwrap();
+
+    axum::serve(listener, app.into_make_service())
+        .await
+        .unwrap();
+}
+
+async fn index() -> impl IntoResponse {
+    let visitors = StatCard::new("Visitors", "1,024", Color::Success, icons::fa::USERS)
+        .with_sparkline(
+            Sparkline::new([620, 710, 680, 840, 790, 960, 1024])
+                .with_color(Color::Success)
+                .with_label("Last 7 days")
+                .filled(),
+        );
+
+    let errors = StatCard::new(
+        "Error Rate",
+        "0.4%",
+        Color::Warning,
+        icons::fa::EXCLAMATION_TRIANGLE,
+    )
+    .with_sparkline(
+        Sparkline::new([1.2, 0.9, 1.1, 0.6, 0.5, 0.4])
+            .with_color(Color::Warning)
+            .with_format(ValueFormat::new().with_suffix("%")),
+    );
+
+    let earnings = SvgChart::area()
+        .with_labels([
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ])
+        .with_dataset(Dataset::new(
+            "Earnings",
+            [
+                0, 10000, 5000, 15000, 10000, 20000, 15000, 25000, 20000, 30000, 25000, 40000,
+            ],
+        ))
+        .with_format(ValueFormat::new().with_prefix("$"));
+
+    let sources = SvgChart::doughnut()
+        .with_labels(["Direct", "Social", "Referral"])
+        .with_dataset(Dataset::new("Sources", [55, 30, 15]))
+        .with_format(ValueFormat::new().with_suffix("%"))
+        .with_size(300, 300)
+        .with_legend();
+
+    let regions = SvgChart::horizontal_bar()
+        .with_labels(["eu-west", "eu-north", "us-east", "us-west", "ap-south"])
+        .with_dataset(Dataset::new("Servers", [42, 17, 58, 33, 9]).with_color(Color::Info));
+
+    let latency = SvgChart::line()
+        .with_labels(["00:00", "04:00", "08:00", "12:00", "16:00", "20:00"])
+        .with_dataset(Dataset::new("p50", [12.5, 11.0, 18.2, 25.1, 21.3, 15.8]))
+        .with_dataset(Dataset::new("p99", [45.0, 38.2, 80.4, 120.9, 97.0, 61.6]))
+        .with_format(ValueFormat::new().with_suffix(" ms"))
+        .with_legend();
+
+    let content = grid! {
+        row {
+            col(xl = 3, md = 6) => visitors,
+            col(xl = 3, md = 6) => errors,
+        }
+        row {
+            col(xl = 8) => Card::new(earnings).with_header("Earnings Overview"),
+            col(xl = 4) => Card::new(sources).with_header("Revenue Sources"),
+        }
+        row {
+            col(lg = 6) => Card::new(regions).with_header("Servers per Region"),
+            col(lg = 6) => Card::new(latency).with_header("Latency"),
+        }
+    };
+
+    Html(
+        Page::new("SVG Charts", "/static-path/nested")
+            .with_content(Dashboard::default().replace_content(content))
+            .to_string(),
+    )
+}
@@ -0,0 +1,130 @@
+use axum::{
+    response::{Html, IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use bootstrap_dashboard::{
+    card::Card,
+    table::{Alignment, ExportFormat, Table, TableColumn, TableQuery},
+    Dashboard, Page,
+};
+use tokio::net::TcpListener;
+
+const PAGE_SIZE: usize = 15;
+
+struct Server {
+    name: String,
+    region: &'static str,
+    load: u32,
+    online: bool,
+}
+
+#[tokio::main]
+async fn main() {
+    let app = Router::new()
+        .route("/", get(index))
+        .merge(bootstrap_dashboard::files::serve_at(
+            "/static-path/nested/*path",
+        ));
+
+    println!("Example running at http://localhost:3000");
+
+    let listener = TcpListener::bind("0.0.0.0:3000").await.unwrap();
+
+    axum::serve(listener, app.into_make_service())
+        .await
+        .unwrap();
+}
+
+fn servers() -> Vec<Server> {
+    let regions = ["eu-west", "eu-north", "us-east", "us-west", "ap-south"];
+
+    (1..=137)
+        .map(|i| Server {
+            name: format!("server-{i:03}"),
+            region: regions[i % regions.len()],
+            load: (i as u32 * 37) % 100,
+            online: i % 11 != 0,
+        })
+        .collect()
+}
+
+async fn index(query: TableQuery) -> Response {
+    let mut servers: Vec<_> = servers()
+        .into_iter()
+        .filter(|server| match &query.search {
+            Some(search) => server.name.contains(search) || server.region.contains(search),
+            None => true,
+        })
+        .collect();
+
+    match query.sort.as_deref() {
+        Some("region") => servers.sort_by(|a, b| a.region.cmp(b.region)),
+        Some("load") => servers.sort_by_key(|server| server.load),
+        _ => servers.sort_by(|a, b| a.name.cmp(&b.name)),
+    }
+
+    if query.descending {
+        servers.reverse();
+    }
+
+    let table = Table::new("servers", "/")
+        .with_column(
+            TableColumn::new("name", "Name", |server: &Server| server.name.clone()).sortable(),
+        )
+        .with_column(
+            TableColumn::new("region", "Region", |server: &Server| server.region).sortable(),
+        )
+        .with_column(
+            TableColumn::new("load", "Load", |server: &Server| {
+                format!("{}%", server.load)
+            })
+            .with_export(|server: &Server| server.load)
+            .with_align(Alignment::Right)
+            .sortable(),
+        )
+        .with_column(
+            TableColumn::markup("status", "Status", |server: &Server| {
+                if server.online {
+                    r#"<span class="badge badge-success">Online</span>"#
+                } else {
+                    r#"<span class="badge badge-danger">Offline</span>"#
+                }
+            })
+            .with_align(Alignment::Center)
+            .with_export(|server: &Server| server.online),
+        )
+        .with_query(query.clone())
+        .with_export(ExportFormat::Csv)
+        .with_export(ExportFormat::Json);
+
+    if let Some(format) = query.export {
+        return table.with_rows(servers).into_export(format).into_response();
+    }
+
+    let total = servers.len();
+    let page = servers
+        .into_iter()
+        .skip(query.offset(PAGE_SIZE))
+        .take(PAGE_SIZE);
+
+    let table = table
+        .with_rows(page)
+        .with_pagination(total, PAGE_SIZE)
+        .searchable()
+        .striped()
+        .hover();
+
+    Html(
+        Page::new("Servers", "/static-path/nested")
+            .with_content(
+                Dashboard::default().replace_content(
+                    Card::new(table)
+                        .with_header("Servers")
+                        .with_export_buttons(),
+                ),
+            )
+            .to_string(),
+    )
+    .into_response()
+}
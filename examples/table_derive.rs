@@ -0,0 +1,101 @@
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::{Html, IntoResponse},
+    routing::get,
+    Router,
+};
+use bootstrap_dashboard::{
+    card::Card,
+    table::{DashboardTable, TableQuery},
+    Dashboard, Page,
+};
+use tokio::net::TcpListener;
+
+#[derive(DashboardTable)]
+struct User {
+    #[table(label = "#", link = "/users/{id}", sortable)]
+    id: u32,
+    #[table(sortable)]
+    display_name: String,
+    #[table(format = "{:.1}%", align = "right", sortable)]
+    quota_used: f32,
+    #[table(hidden)]
+    email: Option<String>,
+    #[table(skip)]
+    #[allow(dead_code)]
+    password_hash: String,
+}
+
+#[tokio::main]
+async fn main() {
+    let app = Router::new()
+        .route("/", get(index))
+        .route("/users/:id", get(details))
+        .merge(bootstrap_dashboard::files::serve_at(
+            "/static-path/nested/*path",
+        ));
+
+    println!("Example running at http://localhost:3000");
+
+    let listener = TcpListener::bind("0.0.0.0:3000").await.unwrap();
+
+    axum::serve(listener, app.into_make_service())
+        .await
+        .unwrap();
+}
+
+fn users() -> Vec<User> {
+    ["Alice", "Bob", "Carol", "Dave", "Eve"]
+        .into_iter()
+        .zip(1..)
+        .map(|(name, id)| User {
+            id,
+            display_name: name.to_string(),
+            quota_used: (id * 17 % 100) as f32 + 0.25,
+            email: (id % 2 == 1).then(|| format!("{}@example.com", name.to_lowercase())),
+            password_hash: String::from("..."),
+        })
+        .collect()
+}
+
+async fn index(query: TableQuery) -> impl IntoResponse {
+    let mut users = users();
+
+    match query.sort.as_deref() {
+        Some("display_name") => users.sort_by(|a, b| a.display_name.cmp(&b.display_name)),
+        Some("quota_used") => users.sort_by(|a, b| a.quota_used.total_cmp(&b.quota_used)),
+        _ => users.sort_by_key(|user| user.id),
+    }
+
+    if query.descending {
+        users.reverse();
+    }
+
+    let table = User::table("users", "/")
+        .with_rows(users)
+        .with_query(query)
+        .hover();
+
+    Html(
+        Page::new("Users", "/static-path/nested")
+            .with_content(
+                Dashboard::default().replace_content(Card::new(table).with_header("Users")),
+            )
+            .to_string(),
+    )
+}
+
+async fn details(Path(id): Path<u32>) -> impl IntoResponse {
+    let Some(user) = users().into_iter().find(|user| user.id == id) else {
+        return Err(StatusCode::NOT_FOUND);
+    };
+
+    let card = Card::new(user.details()).with_header(user.display_name.clone());
+
+    Ok(Html(
+        Page::new("User", "/static-path/nested")
+            .with_content(Dashboard::default().replace_content(card))
+            .to_string(),
+    ))
+}
@@ -0,0 +1,95 @@
+use std::borrow::Cow;
+
+use axum::{
+    extract::OriginalUri,
+    response::{Html, IntoResponse},
+    routing::get,
+    Router,
+};
+use bootstrap_dashboard::{
+    icons, route::Route, Dashboard, Group, IconLink, LinkAction, Page, Sidebar,
+};
+use tokio::net::TcpListener;
+
+/// A single definition of the application's pages, used both for
+/// registering the routes and for building the sidebar.
+#[derive(Clone, Copy)]
+enum AppRoute {
+    Dashboard,
+    Users,
+    Settings,
+}
+
+impl AppRoute {
+    const ALL: [AppRoute; 3] = [AppRoute::Dashboard, AppRoute::Users, AppRoute::Settings];
+
+    fn label(&self) -> &'static str {
+        match self {
+            AppRoute::Dashboard => "Dashboard",
+            AppRoute::Users => "Users",
+            AppRoute::Settings => "Settings",
+        }
+    }
+}
+
+impl Route for AppRoute {
+    fn path(&self) -> Cow<'static, str> {
+        match self {
+            AppRoute::Dashboard => "/".into(),
+            AppRoute::Users => "/users".into(),
+            AppRoute::Settings => "/settings".into(),
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let app = AppRoute::ALL
+        .iter()
+        .fold(Router::new(), |router, route| {
+            router.route(&route.path(), get(page))
+        })
+        .merge(bootstrap_dashboard::files::serve_at(
+            "/static-path/nested/*path",
+        ));
+
+    println!("Example running at http://localhost:3000");
+
+    let listener = TcpListener::bind("0.0.0.0:3000").await.unwrap();
+
+    axum::serve(listener, app.into_make_service())
+        .await
+        .unwrap();
+}
+
+async fn page(OriginalUri(uri): OriginalUri) -> impl IntoResponse {
+    let current = AppRoute::ALL
+        .into_iter()
+        .find(|route| route.matches(uri.path()))
+        .unwrap_or(AppRoute::Dashboard);
+
+    let sidebar = Sidebar::new("Dashboard", icons::fa::LAUGH_SQUINT)
+        .with_group(
+            AppRoute::ALL
+                .iter()
+                .fold(Group::unlabeled(), |group, route| {
+                    group.with_item(IconLink::new(
+                        route.label(),
+                        icons::fa::LINK,
+                        LinkAction::route(route),
+                    ))
+                }),
+        )
+        .with_active_route(&current);
+
+    Html(
+        Page::new(current.label(), "/static-path/nested")
+            .with_content(
+                Dashboard::default()
+                    .with_sidebar(sidebar)
+                    .with_page_header(current.label())
+                    .replace_content(format!("You are visiting {}", current.url())),
+            )
+            .to_string(),
+    )
+}
@@ -0,0 +1,174 @@
+//! Reading single fields out of submitted form bodies, for middleware which
+//! needs to look at a form before the handler does.
+use axum::{
+    body::{to_bytes, Body, Bytes},
+    extract::Request,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+};
+use futures_util::{stream, StreamExt};
+
+use crate::route::decode_component;
+
+/// Largest form body buffered while looking for a field.
+const BODY_LIMIT: usize = 2 * 1024 * 1024;
+
+/// Largest part of a multipart body read while looking for a field.
+///
+/// Fields are expected before any uploaded files, as rendered by
+/// [`Form`](crate::form::Form), so the rest of the body is streamed through
+/// untouched instead of being buffered.
+const MULTIPART_LIMIT: usize = 64 * 1024;
+
+/// Find the value of the `name` field of a url-encoded or multipart form body.
+///
+/// The body is put back into the returned request, so the handler can still
+/// extract the whole form. Bodies which can no longer be passed on intact are
+/// rejected.
+pub(crate) async fn form_field(
+    request: Request,
+    name: &str,
+) -> Result<(Request, Option<String>), Response> {
+    let content_type = request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    if content_type.starts_with("application/x-www-form-urlencoded") {
+        urlencoded_field(request, name).await
+    } else if let Some(boundary) = multipart_boundary(content_type) {
+        let boundary = boundary.to_string();
+        multipart_field(request, &boundary, name).await
+    } else {
+        Ok((request, None))
+    }
+}
+
+async fn urlencoded_field(
+    request: Request,
+    name: &str,
+) -> Result<(Request, Option<String>), Response> {
+    let (parts, body) = request.into_parts();
+    let Ok(bytes) = to_bytes(body, BODY_LIMIT).await else {
+        return Err(StatusCode::PAYLOAD_TOO_LARGE.into_response());
+    };
+
+    let value = std::str::from_utf8(&bytes).ok().and_then(|body| {
+        body.split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(field, _)| decode_component(field) == name)
+            .map(|(_, value)| decode_component(value).into_owned())
+    });
+
+    Ok((Request::from_parts(parts, Body::from(bytes)), value))
+}
+
+async fn multipart_field(
+    request: Request,
+    boundary: &str,
+    name: &str,
+) -> Result<(Request, Option<String>), Response> {
+    let (parts, body) = request.into_parts();
+    let mut chunks = body.into_data_stream();
+    let mut read = Vec::new();
+    let mut value = None;
+
+    while value.is_none() && read.len() < MULTIPART_LIMIT {
+        match chunks.next().await {
+            Some(Ok(chunk)) => {
+                read.extend_from_slice(&chunk);
+                value = multipart_value(&read, boundary, name);
+            }
+            Some(Err(_)) => return Err(StatusCode::BAD_REQUEST.into_response()),
+            None => break,
+        }
+    }
+
+    let body = Body::from_stream(stream::iter([Ok(Bytes::from(read))]).chain(chunks));
+
+    Ok((Request::from_parts(parts, body), value))
+}
+
+/// Boundary of a `multipart/form-data` content type.
+fn multipart_boundary(content_type: &str) -> Option<&str> {
+    let (mime, params) = content_type.split_once(';')?;
+    if !mime.trim().eq_ignore_ascii_case("multipart/form-data") {
+        return None;
+    }
+
+    params
+        .split(';')
+        .filter_map(|param| param.split_once('='))
+        .find(|(key, _)| key.trim().eq_ignore_ascii_case("boundary"))
+        .map(|(_, boundary)| boundary.trim().trim_matches('"'))
+        .filter(|boundary| !boundary.is_empty())
+}
+
+/// Value of the `name` field, if its part has been read completely.
+fn multipart_value(read: &[u8], boundary: &str, name: &str) -> Option<String> {
+    let delimiter = format!("\r\n--{boundary}");
+    // The first delimiter is not preceded by a line break.
+    let read = [b"\r\n".as_slice(), read].concat();
+    let mut parts = split(&read, delimiter.as_bytes()).skip(1).peekable();
+
+    while let Some(part) = parts.next() {
+        // The last part is only complete once the next delimiter has been read.
+        parts.peek()?;
+
+        let Some(split_at) = find(part, b"\r\n\r\n") else {
+            continue;
+        };
+        let headers = String::from_utf8_lossy(&part[..split_at]);
+
+        if disposition_name(&headers) == Some(name) {
+            return Some(String::from_utf8_lossy(&part[split_at + 4..]).into_owned());
+        }
+    }
+
+    None
+}
+
+/// `name` parameter of the `Content-Disposition` header of a multipart part.
+fn disposition_name(headers: &str) -> Option<&str> {
+    headers.split("\r\n").find_map(|line| {
+        let (header, value) = line.split_once(':')?;
+        if !header.trim().eq_ignore_ascii_case("content-disposition") {
+            return None;
+        }
+
+        value
+            .split(';')
+            .filter_map(|param| param.split_once('='))
+            .find(|(key, _)| key.trim() == "name")
+            .map(|(_, name)| name.trim().trim_matches('"'))
+    })
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn split<'a>(mut haystack: &'a [u8], needle: &'a [u8]) -> impl Iterator<Item = &'a [u8]> {
+    let mut done = false;
+
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+
+        match find(haystack, needle) {
+            Some(index) => {
+                let item = &haystack[..index];
+                haystack = &haystack[index + needle.len()..];
+                Some(item)
+            }
+            None => {
+                done = true;
+                Some(haystack)
+            }
+        }
+    })
+}
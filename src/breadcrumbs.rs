@@ -0,0 +1,52 @@
+use std::borrow::Cow;
+
+use askama::Template;
+
+use crate::LinkAction;
+
+/// Single entry in a [`Breadcrumbs`] trail.
+#[derive(Debug, Clone)]
+pub struct Breadcrumb {
+    pub label: Cow<'static, str>,
+    /// Where the breadcrumb leads, if anywhere.
+    pub action: Option<LinkAction>,
+}
+
+/// Trail of labels leading to the current page, such as `Settings / Users / List`.
+///
+/// The last breadcrumb is always rendered as the active page, regardless of
+/// whether it has an action.
+#[derive(Debug, Clone, Default, Template)]
+#[template(path = "breadcrumbs.html")]
+pub struct Breadcrumbs(pub Vec<Breadcrumb>);
+
+impl Breadcrumbs {
+    pub fn new() -> Self {
+        Breadcrumbs(Vec::new())
+    }
+
+    pub fn with_label<S: Into<Cow<'static, str>>>(mut self, label: S) -> Self {
+        self.0.push(Breadcrumb {
+            label: label.into(),
+            action: None,
+        });
+        self
+    }
+
+    pub fn with_link<S: Into<Cow<'static, str>>>(mut self, label: S, action: LinkAction) -> Self {
+        self.0.push(Breadcrumb {
+            label: label.into(),
+            action: Some(action),
+        });
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The breadcrumb representing the current page.
+    pub fn last(&self) -> Option<&Breadcrumb> {
+        self.0.last()
+    }
+}
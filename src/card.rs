@@ -2,15 +2,42 @@ use std::{borrow::Cow, fmt::Display};
 
 use askama::Template;
 
-use crate::{Color, LinkAction, PlainLink};
+use crate::{
+    cookies::{self, CookieState},
+    htmx::{Dynamic, HxSwap, Target, TriggerEvent},
+    icons,
+    markup::content_id,
+    svg::Sparkline,
+    table::Table,
+    Color, Icon, LinkAction, PlainLink, Text,
+};
 
 #[derive(Template)]
 #[template(path = "card.html")]
 pub struct Card<Content: Display = &'static str> {
+    id: Option<Cow<'static, str>>,
     header: Option<Cow<'static, str>>,
     buttons: Vec<CardButton>,
     context_links: Vec<ContextGroup>,
     content: Content,
+    footer: Option<Box<dyn Display + Send + Sync>>,
+    image: Option<CardImage>,
+    collapse: Option<Collapse>,
+    header_color: Option<Color>,
+    background: Option<Color>,
+    border: bool,
+    shadow: bool,
+}
+
+/// Image shown at the top of a [`Card`].
+pub struct CardImage {
+    pub src: Cow<'static, str>,
+    pub alt: Cow<'static, str>,
+}
+
+struct Collapse {
+    id: Cow<'static, str>,
+    collapsed: bool,
 }
 
 /// Context menu [`ContextGroup`]s have optional labels, and are always
@@ -19,8 +46,8 @@ pub struct Card<Content: Display = &'static str> {
 pub struct ContextGroup {
     /// Optional Group label.
     pub label: Option<Cow<'static, str>>,
-    /// Group's navigation items.
-    pub items: Vec<PlainLink>,
+    /// Group's menu items.
+    pub items: Vec<ContextItem>,
     /// Optional color for the text,
     pub color: Option<Color>,
 }
@@ -40,21 +67,104 @@ impl ContextGroup {
     }
 
     pub fn with_link<S: Into<Cow<'static, str>>>(mut self, label: S, action: LinkAction) -> Self {
-        self.items.push(PlainLink::new(label, action));
+        self.items.push(ContextItem::new(label, action));
+        self
+    }
+
+    pub fn with_item<I: Into<ContextItem>>(mut self, item: I) -> Self {
+        self.items.push(item.into());
         self
     }
 }
 
+/// Entry of a [`ContextGroup`].
+///
+/// Any [`LinkAction`] can be used, such as [`LinkAction::modal`] to open a
+/// confirmation dialog, or [`LinkAction::hx`] to issue an htmx request.
+#[derive(Template)]
+#[template(
+    ext = "html",
+    source = r##"
+{% if disabled %}
+<a class="dropdown-item disabled" href="#" tabindex="-1" aria-disabled="true">
+{% else %}
+<a class="dropdown-item{% match color %}{% when Some with (color) %} text-{{ color }}{% when None %}{% endmatch %}" href="{{ action.href() }}" {{ action.props()|safe }}>
+{% endif %}
+{% match icon %}
+{% when Some with (icon) %}
+<i class="fas {{ icon }} fa-sm fa-fw mr-2{% if color.is_none() %} text-gray-400{% endif %}"></i>
+{% when None %}
+{% endmatch %}
+{{ label }}{{ action.suffix()|safe }}</a>
+"##
+)]
+pub struct ContextItem {
+    pub label: Cow<'static, str>,
+    pub action: LinkAction,
+    pub icon: Option<Icon>,
+    pub color: Option<Color>,
+    pub disabled: bool,
+}
+
+impl ContextItem {
+    pub fn new<S: Into<Cow<'static, str>>>(label: S, action: LinkAction) -> Self {
+        ContextItem {
+            label: label.into(),
+            action,
+            icon: None,
+            color: None,
+            disabled: false,
+        }
+    }
+
+    pub fn with_icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Show the item greyed out, without performing its action.
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+}
+
+impl From<PlainLink> for ContextItem {
+    fn from(link: PlainLink) -> Self {
+        ContextItem::new(link.label, link.action)
+    }
+}
+
 impl<Content: Display> Card<Content> {
     pub fn new(content: Content) -> Self {
         Card {
+            id: None,
             header: None,
             buttons: Vec::new(),
             context_links: Vec::new(),
             content,
+            footer: None,
+            image: None,
+            collapse: None,
+            header_color: None,
+            background: None,
+            border: true,
+            shadow: true,
         }
     }
 
+    /// Id of the card, which must be unique. Ids of elements within the
+    /// card, such as its context menu, are derived from it.
+    pub fn with_id<S: Into<Cow<'static, str>>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     pub fn with_header<S: Into<Cow<'static, str>>>(mut self, header: S) -> Self {
         self.header = Some(header.into());
         self
@@ -69,30 +179,387 @@ impl<Content: Display> Card<Content> {
         self.buttons.push(button);
         self
     }
+
+    pub fn with_footer<D: Display + Send + Sync + 'static>(mut self, footer: D) -> Self {
+        self.footer = Some(Box::new(footer));
+        self
+    }
+
+    pub fn with_image<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        mut self,
+        src: S1,
+        alt: S2,
+    ) -> Self {
+        self.image = Some(CardImage {
+            src: src.into(),
+            alt: alt.into(),
+        });
+        self
+    }
+
+    /// Allow collapsing the body and footer by clicking the header.
+    ///
+    /// The `id` must be unique, and is used to remember the state of the
+    /// card across page loads, see [`CardState`].
+    pub fn collapsible<S: Into<Cow<'static, str>>>(mut self, id: S) -> Self {
+        self.collapse = Some(Collapse {
+            id: id.into(),
+            collapsed: false,
+        });
+        self
+    }
+
+    /// Initially collapse a [`collapsible`](Card::collapsible) card.
+    pub fn collapsed(mut self) -> Self {
+        if let Some(collapse) = &mut self.collapse {
+            collapse.collapsed = true;
+        }
+        self
+    }
+
+    /// Collapse or expand a [`collapsible`](Card::collapsible) card the way
+    /// the user last left it. Cards the user never toggled keep their
+    /// initial state.
+    pub fn with_state(mut self, state: &CardState) -> Self {
+        if let Some(collapse) = &mut self.collapse {
+            let toggled = |ids: &[String]| ids.iter().any(|id| *id == collapse.id);
+
+            if toggled(&state.collapsed) {
+                collapse.collapsed = true;
+            } else if toggled(&state.expanded) {
+                collapse.collapsed = false;
+            }
+        }
+        self
+    }
+
+    pub fn with_header_color(mut self, color: Color) -> Self {
+        self.header_color = Some(color);
+        self
+    }
+
+    pub fn with_background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    pub fn without_border(mut self) -> Self {
+        self.border = false;
+        self
+    }
+
+    pub fn without_shadow(mut self) -> Self {
+        self.shadow = false;
+        self
+    }
+
+    fn classes(&self) -> String {
+        let mut classes = String::from("card");
+
+        if self.shadow {
+            classes.push_str(" shadow");
+        }
+
+        if !self.border {
+            classes.push_str(" border-0");
+        }
+
+        if let Some(color) = self.background {
+            classes.push(' ');
+            classes.push_str(color.as_background());
+            classes.push_str(" text-white");
+        }
+
+        classes
+    }
+
+    fn header_classes(&self) -> String {
+        let mut classes = String::from(
+            "card-header py-3 d-flex flex-row align-items-center justify-content-between",
+        );
+
+        if let Some(color) = self.header_color {
+            classes.push(' ');
+            classes.push_str(color.as_background());
+        }
+
+        if let Some(Collapse {
+            collapsed: true, ..
+        }) = self.collapse
+        {
+            classes.push_str(" collapsed");
+        }
+
+        classes
+    }
+
+    /// Id of the context menu toggle, derived from the card or collapse id,
+    /// or else from the header, buttons and menu items.
+    fn menu_id(&self) -> String {
+        if let Some(id) = &self.id {
+            return format!("{id}-menu");
+        }
+
+        if let Some(collapse) = &self.collapse {
+            return format!("{}-menu", collapse.id);
+        }
+
+        let parts: Vec<String> = self
+            .buttons
+            .iter()
+            .map(ToString::to_string)
+            .chain(self.context_links.iter().flat_map(|group| {
+                group
+                    .label
+                    .iter()
+                    .map(ToString::to_string)
+                    .chain(group.items.iter().map(ToString::to_string))
+            }))
+            .collect();
+
+        content_id(
+            "cardMenu",
+            self.header
+                .as_deref()
+                .into_iter()
+                .chain(parts.iter().map(String::as_str)),
+        )
+    }
+
+    fn header_text_color(&self) -> &'static str {
+        if self.header_color.is_some() || self.background.is_some() {
+            "text-white"
+        } else {
+            "text-primary"
+        }
+    }
+}
+
+impl Card<LazyBody> {
+    /// Card whose body is fetched from `url` once it is scrolled into view,
+    /// showing a spinner while loading and an error message if it fails.
+    pub fn lazy<S: Into<Cow<'static, str>>>(url: S) -> Self {
+        Card::new(LazyBody::new(url))
+    }
+
+    /// Give the body an id, such as to target it from other htmx requests.
+    pub fn with_body_id<S: Into<Cow<'static, str>>>(mut self, id: S) -> Self {
+        self.content.id = Some(id.into());
+        self
+    }
+
+    /// Fetch the body as soon as the page has loaded, rather than once revealed.
+    pub fn load_immediately(mut self) -> Self {
+        self.content.trigger = TriggerEvent::Load;
+        self
+    }
+
+    /// Message shown when the body fails to load.
+    pub fn with_error_message<S: Into<Cow<'static, str>>>(mut self, message: S) -> Self {
+        self.content.error = message.into();
+        self
+    }
+
+    /// Add a header button fetching the body again.
+    ///
+    /// The button targets the next lazy body after it, which is the one of
+    /// its own card, so cards loaded from the same url need no distinct ids.
+    pub fn with_refresh_button(self) -> Self {
+        let refresh = CardButton::new("Refresh")
+            .with_icon(icons::fa::SYNC_ALT)
+            .with_outline()
+            .with_hx(
+                HxSwap::get(self.content.url.clone())
+                    .with_target(Target::Next("[data-lazy-body]".into())),
+            );
+
+        self.with_button(refresh)
+    }
+}
+
+impl<Row> Card<Table<Row>> {
+    /// Add the [`Table::export_buttons`] to the header.
+    pub fn with_export_buttons(mut self) -> Self {
+        self.buttons.extend(self.content.export_buttons());
+        self
+    }
 }
 
+/// Body of a [`Card::lazy`] card, loaded using htmx.
+#[derive(Debug, Clone, Template)]
+#[template(
+    ext = "html",
+    source = r#"
+<div{% match id %}{% when Some with (id) %} id="{{ id }}"{% when None %}{% endmatch %} data-lazy-body data-error-message="{{ error }}" {{ self.hx()|safe }}>
+    <div class="d-flex justify-content-center py-4">
+        <div class="spinner-border text-gray-400" role="status">
+            <span class="sr-only">Loading...</span>
+        </div>
+    </div>
+</div>
+"#
+)]
+pub struct LazyBody {
+    pub id: Option<Cow<'static, str>>,
+    pub url: Cow<'static, str>,
+    pub trigger: TriggerEvent,
+    pub error: Cow<'static, str>,
+}
+
+impl LazyBody {
+    /// Body fetched from `url`.
+    pub fn new<S: Into<Cow<'static, str>>>(url: S) -> Self {
+        LazyBody {
+            id: None,
+            url: url.into(),
+            trigger: TriggerEvent::Revealed,
+            error: "Failed to load content.".into(),
+        }
+    }
+
+    /// Give the body an id, such as to target it from other htmx requests.
+    pub fn with_id<S: Into<Cow<'static, str>>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    fn hx(&self) -> HxSwap {
+        HxSwap::get(self.url.clone()).with_trigger(self.trigger)
+    }
+}
+
+/// Collapsed and expanded [`Card`]s, persisted in cookies by the bundled
+/// `card-state.js`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CardState {
+    /// Ids of the cards the user collapsed, see [`Card::collapsible`].
+    pub collapsed: Vec<String>,
+    /// Ids of the cards the user expanded.
+    pub expanded: Vec<String>,
+}
+
+impl CardState {
+    /// Cookie containing the `.`-separated ids of collapsed cards.
+    pub const COLLAPSED_COOKIE: &'static str = "cardsCollapsed";
+    /// Cookie containing the `.`-separated ids of expanded cards.
+    pub const EXPANDED_COOKIE: &'static str = "cardsExpanded";
+
+    /// Parse the state from the value of a `Cookie` header.
+    pub fn from_cookie_header(header: &str) -> Self {
+        cookies::from_header(header)
+    }
+}
+
+impl CookieState for CardState {
+    fn apply_cookie(&mut self, name: &str, value: &str) {
+        match name {
+            Self::COLLAPSED_COOKIE => self.collapsed.extend(cookies::ids(value)),
+            Self::EXPANDED_COOKIE => self.expanded.extend(cookies::ids(value)),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(feature = "axum")]
+mod axum_state {
+    use std::convert::Infallible;
+
+    use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+
+    use super::CardState;
+
+    /// Extracts the [`CardState`] from the request cookies.
+    #[async_trait]
+    impl<S: Send + Sync> FromRequestParts<S> for CardState {
+        type Rejection = Infallible;
+
+        async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+            Ok(crate::cookies::from_headers(&parts.headers))
+        }
+    }
+}
+
+/// Small button, rendered as a link if it has an action.
+///
+/// Used in [`Card`] headers, as well as [`PageHeader`](crate::PageHeader)
+/// and [`SidebarCard`](crate::SidebarCard) actions.
+#[derive(Template)]
+#[template(
+    ext = "html",
+    source = r#"
+{% match action %}
+{% when Some with (action) %}
+<a {% match id %}{% when Some with (id) %}id="{{ id }}" {% when None %}{% endmatch %}class="btn btn-sm btn{% if outline %}-outline{% endif %}-{{ color }}" href="{{ action.href() }}" {{ action.props()|safe }} {% match hx %}{% when Some with (hx) %}{{ hx|safe }}{% when None %}{% endmatch %}>
+{% when None %}
+<button type="button" {% match id %}{% when Some with (id) %}id="{{ id }}" {% when None %}{% endmatch %}class="btn btn-sm btn{% if outline %}-outline{% endif %}-{{ color }}" {% match hx %}{% when Some with (hx) %}{{ hx|safe }}{% when None %}{% endmatch %}>
+{% endmatch %}
+{% match icon %}
+{% when Some with (icon) %}
+<i class="fas {{ icon }} fa-sm{% if !outline %} text-white-50{% endif %}"></i>
+{% when None %}
+{% endmatch %}
+{{ label }}
+{% match action %}{% when Some with (action) %}{{ action.suffix()|safe }}{% when None %}{% endmatch %}
+{% if action.is_some() %}</a>{% else %}</button>{% endif %}
+"#
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CardButton {
     pub label: Cow<'static, str>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub id: Option<Cow<'static, str>>,
+    #[cfg_attr(feature = "serde", serde(default))]
     pub color: Color,
+    #[cfg_attr(feature = "serde", serde(default))]
     pub outline: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub icon: Option<Icon>,
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub action: Option<LinkAction>,
+    /// htmx request issued when the button is clicked.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub hx: Option<HxSwap>,
 }
 
 impl CardButton {
     pub fn new<S: Into<Cow<'static, str>>>(label: S) -> Self {
         CardButton {
             label: label.into(),
+            id: None,
             color: Color::Primary,
             outline: false,
+            icon: None,
             action: None,
+            hx: None,
         }
     }
 
+    pub fn with_id<S: Into<Cow<'static, str>>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     pub fn with_action(mut self, action: LinkAction) -> Self {
         self.action = Some(action);
         self
     }
 
+    pub fn with_hx(mut self, hx: HxSwap) -> Self {
+        self.hx = Some(hx);
+        self
+    }
+
+    pub fn with_icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
     pub fn with_outline(mut self) -> Self {
         self.outline = true;
         self
@@ -103,3 +570,296 @@ impl CardButton {
         self
     }
 }
+
+/// Small card with a colored left border, showing a single labeled value.
+///
+/// The value can be kept up to date by polling, using [`StatCard::with_hx`].
+#[derive(Template)]
+#[template(path = "stat_card.html")]
+pub struct StatCard {
+    pub label: Cow<'static, str>,
+    pub value: Dynamic<Text>,
+    pub color: Color,
+    pub icon: Icon,
+    /// Percentage shown as a progress bar next to the value.
+    pub progress: Option<u8>,
+    pub trend: Option<Trend>,
+    pub sparkline: Option<Sparkline>,
+}
+
+impl StatCard {
+    pub fn new<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        label: S1,
+        value: S2,
+        color: Color,
+        icon: Icon,
+    ) -> Self {
+        StatCard {
+            label: label.into(),
+            value: Dynamic::from(Text::new(value)),
+            color,
+            icon,
+            progress: None,
+            trend: None,
+            sparkline: None,
+        }
+    }
+
+    /// Show a progress bar, with `percent` capped at 100.
+    pub fn with_progress(mut self, percent: u8) -> Self {
+        self.progress = Some(percent.min(100));
+        self
+    }
+
+    pub fn with_trend(mut self, trend: Trend) -> Self {
+        self.trend = Some(trend);
+        self
+    }
+
+    /// Show the recent history of the value below it.
+    pub fn with_sparkline(mut self, sparkline: Sparkline) -> Self {
+        self.sparkline = Some(sparkline);
+        self
+    }
+
+    /// Refresh the value using htmx, such as every few seconds.
+    ///
+    /// The response replaces the value, so it should only contain the new value.
+    pub fn with_hx(mut self, hx: HxSwap) -> Self {
+        self.value = self.value.with_hx(hx);
+        self
+    }
+}
+
+/// Change of a [`StatCard`] value, such as compared to the previous month.
+#[derive(Debug, Clone)]
+pub struct Trend {
+    pub delta: Cow<'static, str>,
+    pub direction: TrendDirection,
+    /// Whether a decrease is the desired direction, such as for error rates.
+    pub inverted: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrendDirection {
+    Up,
+    Down,
+    Flat,
+}
+
+impl Trend {
+    pub fn new<S: Into<Cow<'static, str>>>(delta: S, direction: TrendDirection) -> Self {
+        Trend {
+            delta: delta.into(),
+            direction,
+            inverted: false,
+        }
+    }
+
+    pub fn up<S: Into<Cow<'static, str>>>(delta: S) -> Self {
+        Trend::new(delta, TrendDirection::Up)
+    }
+
+    pub fn down<S: Into<Cow<'static, str>>>(delta: S) -> Self {
+        Trend::new(delta, TrendDirection::Down)
+    }
+
+    pub fn flat<S: Into<Cow<'static, str>>>(delta: S) -> Self {
+        Trend::new(delta, TrendDirection::Flat)
+    }
+
+    pub fn inverted(mut self) -> Self {
+        self.inverted = true;
+        self
+    }
+
+    pub fn color(&self) -> Color {
+        match (self.direction, self.inverted) {
+            (TrendDirection::Flat, _) => Color::Secondary,
+            (TrendDirection::Up, false) | (TrendDirection::Down, true) => Color::Success,
+            (TrendDirection::Up, true) | (TrendDirection::Down, false) => Color::Danger,
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self.direction {
+            TrendDirection::Up => "fa-arrow-up",
+            TrendDirection::Down => "fa-arrow-down",
+            TrendDirection::Flat => "fa-minus",
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn stat_card_rendering() {
+    let rendered = StatCard::new("Errors", "<b>3</b>", Color::Warning, crate::icons::fa::BUG)
+        .with_progress(150)
+        .with_trend(Trend::down("2").inverted())
+        .to_string();
+
+    assert!(rendered.contains("border-left-warning"));
+    assert!(rendered.contains("&lt;b&gt;3&lt;/b&gt;"));
+    assert!(rendered.contains(r#"aria-valuenow="100""#));
+    assert!(rendered.contains(r#"<div class="small mt-1 text-success">"#));
+}
+
+#[cfg(test)]
+#[test]
+fn restore_collapsed_card() {
+    let state = CardState::from_cookie_header(
+        "sidebarToggled=true; cardsCollapsed=stats.todo; cardsExpanded=notes",
+    );
+    assert_eq!(state.collapsed, ["stats", "todo"]);
+    assert_eq!(state.expanded, ["notes"]);
+
+    let rendered = Card::new("Body")
+        .with_header("Todo")
+        .with_footer("Footer")
+        .collapsible("todo")
+        .with_state(&state)
+        .to_string();
+
+    assert!(rendered.contains(r##"data-target="#todo""##));
+    assert!(rendered.contains(r#"<div class="collapse" id="todo">"#));
+    assert!(rendered.contains(r#"<div class="card-footer">"#));
+}
+
+#[cfg(test)]
+#[test]
+fn untoggled_card_keeps_initial_state() {
+    let state = CardState::from_cookie_header("cardsCollapsed=stats; cardsExpanded=notes");
+
+    let untouched = Card::new("Body")
+        .with_header("Archive")
+        .collapsible("archive")
+        .collapsed()
+        .with_state(&state)
+        .to_string();
+    assert!(untouched.contains(r#"<div class="collapse" id="archive">"#));
+
+    let expanded = Card::new("Body")
+        .with_header("Notes")
+        .collapsible("notes")
+        .collapsed()
+        .with_state(&state)
+        .to_string();
+    assert!(expanded.contains(r#"<div class="collapse show" id="notes">"#));
+}
+
+#[cfg(test)]
+#[test]
+fn lazy_card_refresh() {
+    let rendered = Card::lazy("/reports")
+        .with_header("Report")
+        .with_refresh_button()
+        .to_string();
+
+    assert_eq!(rendered.matches(r#"hx-get="/reports""#).count(), 2);
+    assert!(rendered.contains(r#"hx-trigger="revealed""#));
+    assert!(rendered.contains(r#"hx-target="next [data-lazy-body]""#));
+}
+
+#[cfg(test)]
+#[test]
+fn lazy_cards_sharing_url() {
+    let rendered = crate::grid::Row::new()
+        .with_column(
+            Card::lazy("/reports")
+                .with_header("Daily")
+                .with_refresh_button(),
+        )
+        .with_column(
+            Card::lazy("/reports")
+                .with_header("Weekly")
+                .with_refresh_button()
+                .with_body_id("weekly"),
+        )
+        .to_string();
+
+    assert_eq!(rendered.matches(" id=").count(), 1);
+    assert!(rendered.contains(r#"<div id="weekly" data-lazy-body"#));
+
+    // Each refresh button is followed by the body of its own card.
+    let parts: Vec<_> = rendered
+        .split(r#"hx-target="next [data-lazy-body]""#)
+        .collect();
+    assert_eq!(parts.len(), 3);
+    assert!(parts[1].contains("Weekly"));
+    assert_eq!(parts[1].matches("data-lazy-body").count(), 1);
+    assert_eq!(parts[2].matches("data-lazy-body").count(), 1);
+}
+
+#[cfg(test)]
+#[test]
+fn deterministic_ids() {
+    let card = |url: &'static str| {
+        Card::lazy(url)
+            .with_header("Report")
+            .with_context_group(
+                ContextGroup::default().with_item(ContextItem::new("Open", LinkAction::to(url))),
+            )
+            .with_refresh_button()
+            .to_string()
+    };
+
+    assert_eq!(card("/reports"), card("/reports"));
+    assert_ne!(card("/reports"), card("/reports/2024"));
+
+    let chart = || {
+        crate::chart::Chart::bar()
+            .with_labels(["Jan", "Feb"])
+            .to_string()
+    };
+    assert_eq!(chart(), chart());
+}
+
+#[cfg(test)]
+#[test]
+fn context_menu_items() {
+    let card = || {
+        Card::new("Body").with_header("Menu").with_context_group(
+            ContextGroup::default()
+                .with_item(
+                    ContextItem::new("Delete", LinkAction::modal_name("deleteModal"))
+                        .with_color(Color::Danger),
+                )
+                .with_item(ContextItem::new("Archive", LinkAction::to("/archive")).disabled()),
+        )
+    };
+
+    let first = card().to_string();
+    let menu_ids = |rendered: &str| -> Vec<String> {
+        rendered
+            .split(r#"aria-labelledby=""#)
+            .skip(1)
+            .map(|rest| rest[..rest.find('"').unwrap()].to_string())
+            .collect()
+    };
+
+    // Cards on one page with the same header get different menu ids.
+    let page = crate::grid::Row::new()
+        .with_column(card())
+        .with_column(
+            Card::new("Other").with_header("Menu").with_context_group(
+                ContextGroup::default()
+                    .with_item(ContextItem::new("Archive", LinkAction::to("/other"))),
+            ),
+        )
+        .with_column(card().with_id("third"))
+        .to_string();
+    let ids = menu_ids(&page);
+    assert_eq!(ids.len(), 3);
+    assert_ne!(ids[0], ids[1]);
+    assert_eq!(ids[2], "third-menu");
+    assert!(page.contains(r#"<div id="third" class="card"#));
+    assert_eq!(menu_ids(&first), menu_ids(&card().to_string()));
+    assert_eq!(
+        menu_ids(&card().collapsible("menu").to_string()),
+        ["menu-menu"]
+    );
+    assert!(first.contains(r##"data-toggle="modal" data-target="#deleteModal""##));
+    assert!(first.contains(r#"class="dropdown-item text-danger""#));
+    assert!(first.contains(r#"class="dropdown-item disabled""#));
+    assert!(!first.contains("/archive"));
+}
@@ -0,0 +1,506 @@
+//! Charts drawn by [Chart.js](https://www.chartjs.org/), styled like the
+//! SB-Admin-2 charts page.
+//!
+//! A [`Chart`] renders a `<canvas>` carrying its configuration as JSON,
+//! which is picked up by `charts.js` from the [`STATIC_FILES`](crate::files::STATIC_FILES).
+//! Chart.js itself is only loaded once a page contains a chart, from
+//! `js/Chart.min.js` next to `charts.js`. Until then, or if it is missing,
+//! the chart is shown as an [`SvgChart`](crate::svg::SvgChart) instead.
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use bootstrap_dashboard::{
+//!     chart::{Chart, Dataset, ValueFormat},
+//!     Color,
+//! };
+//!
+//! let chart = Chart::area()
+//!     .with_labels(["Jan", "Feb", "Mar"])
+//!     .with_dataset(Dataset::new("Earnings", [1200.0, 950.5, 1800.0]).with_color(Color::Success))
+//!     .with_format(ValueFormat::new().with_prefix("$").with_decimals(2))
+//!     .with_refresh("/earnings.json", Duration::from_secs(30));
+//!
+//! assert!(chart.to_string().contains("data-chart-refresh=\"/earnings.json\""));
+//! assert_eq!(chart.data().to_string(), r#"{"labels": ["Jan", "Feb", "Mar"], "datasets": [{"data": [1200, 950.5, 1800]}]}"#);
+//! ```
+//!
+//! Charts can also be re-rendered by htmx, such as in a [`Dynamic`](crate::Dynamic)
+//! or a [lazy](crate::card::Card::lazy) card.
+use std::{borrow::Cow, fmt::Display, time::Duration};
+
+use askama::Template;
+
+use crate::{
+    markup::{content_id, json_string},
+    svg::SvgChart,
+    Color,
+};
+
+/// Type of a [`Chart`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartKind {
+    Line,
+    /// Line chart with the area below the line filled.
+    Area,
+    Bar,
+    HorizontalBar,
+    Pie,
+    Doughnut,
+}
+
+impl ChartKind {
+    /// Chart.js chart type.
+    fn chart_type(&self) -> &'static str {
+        match self {
+            ChartKind::Line | ChartKind::Area => "line",
+            ChartKind::Bar => "bar",
+            ChartKind::HorizontalBar => "horizontalBar",
+            ChartKind::Pie => "pie",
+            ChartKind::Doughnut => "doughnut",
+        }
+    }
+
+    /// SB-Admin-2 class sizing the chart container.
+    fn container_class(&self) -> &'static str {
+        match self {
+            ChartKind::Line | ChartKind::Area => "chart-area",
+            ChartKind::Bar | ChartKind::HorizontalBar => "chart-bar",
+            ChartKind::Pie | ChartKind::Doughnut => "chart-pie",
+        }
+    }
+}
+
+/// Series of values of a [`Chart`], one per label.
+#[derive(Debug, Clone)]
+pub struct Dataset {
+    pub label: Cow<'static, str>,
+    pub values: Vec<f64>,
+    /// Color of the series, defaulting to one based on its position.
+    pub color: Option<Color>,
+    /// Colors of the individual slices of pie and doughnut charts.
+    pub colors: Vec<Color>,
+}
+
+impl Dataset {
+    pub fn new<S: Into<Cow<'static, str>>, V: Into<f64>, I: IntoIterator<Item = V>>(
+        label: S,
+        values: I,
+    ) -> Self {
+        Dataset {
+            label: label.into(),
+            values: values.into_iter().map(Into::into).collect(),
+            color: None,
+            colors: Vec::new(),
+        }
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Colors of the slices of pie and doughnut charts, in order.
+    pub fn with_colors<I: IntoIterator<Item = Color>>(mut self, colors: I) -> Self {
+        self.colors = colors.into_iter().collect();
+        self
+    }
+
+    /// Color of the dataset at the given position of a chart.
+    pub(crate) fn series_color(&self, index: usize) -> Color {
+        self.color.unwrap_or(PALETTE[index % PALETTE.len()])
+    }
+
+    pub(crate) fn slice_color(&self, slice: usize) -> Color {
+        self.colors
+            .get(slice)
+            .copied()
+            .unwrap_or(PALETTE[slice % PALETTE.len()])
+    }
+}
+
+/// Formatting of values on the axis and in tooltips, such as `$1,250.00`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValueFormat {
+    pub prefix: Cow<'static, str>,
+    pub suffix: Cow<'static, str>,
+    /// Fixed number of decimals, or as many as needed if `None`.
+    pub decimals: Option<u8>,
+}
+
+impl ValueFormat {
+    pub fn new() -> Self {
+        ValueFormat::default()
+    }
+
+    pub fn with_prefix<S: Into<Cow<'static, str>>>(mut self, prefix: S) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    pub fn with_suffix<S: Into<Cow<'static, str>>>(mut self, suffix: S) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    pub fn with_decimals(mut self, decimals: u8) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// Format the value with thousands separators, as `charts.js` does.
+    pub fn format(&self, value: f64) -> String {
+        let number = match self.decimals {
+            Some(decimals) => format!("{value:.*}", decimals as usize),
+            None => value.to_string(),
+        };
+
+        let (sign, number) = match number.strip_prefix('-') {
+            Some(number) => ("-", number),
+            None => ("", number.as_str()),
+        };
+
+        let (integer, fraction) = match number.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (number, None),
+        };
+
+        let mut grouped = String::with_capacity(integer.len() + integer.len() / 3);
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                grouped.push(',');
+            }
+            grouped.push(digit);
+        }
+
+        match fraction {
+            Some(fraction) => format!("{}{sign}{grouped}.{fraction}{}", self.prefix, self.suffix),
+            None => format!("{}{sign}{grouped}{}", self.prefix, self.suffix),
+        }
+    }
+
+    fn json(&self) -> String {
+        format!(
+            r#"{{"prefix": {}, "suffix": {}, "decimals": {}}}"#,
+            json_string(&self.prefix),
+            json_string(&self.suffix),
+            self.decimals
+                .map_or_else(|| String::from("null"), |decimals| decimals.to_string())
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Refresh {
+    url: Cow<'static, str>,
+    interval: Duration,
+}
+
+/// Line, area, bar, pie or doughnut chart drawn by Chart.js.
+///
+/// Until Chart.js has loaded, or if it cannot be, the same chart is shown
+/// as an [`SvgChart`] rendered on the server.
+#[derive(Debug, Clone, Template)]
+#[template(
+    ext = "html",
+    source = r#"
+<div class="{{ kind.container_class() }}">
+    <canvas id="{{ self.id() }}" data-chart="{{ self.config() }}"{% match refresh %}{% when Some with (refresh) %} data-chart-refresh="{{ refresh.url }}" data-chart-interval="{{ refresh.interval.as_millis() }}"{% when None %}{% endmatch %} hidden></canvas>
+    <div class="chart-fallback">{{ self.fallback()|safe }}</div>
+</div>
+"#
+)]
+pub struct Chart {
+    /// Id of the canvas, derived from the kind and labels of the chart if not set.
+    pub id: Option<Cow<'static, str>>,
+    pub kind: ChartKind,
+    pub labels: Vec<Cow<'static, str>>,
+    pub datasets: Vec<Dataset>,
+    pub format: ValueFormat,
+    /// Show a legend with the dataset labels, or slice labels for pie and doughnut charts.
+    pub legend: bool,
+    refresh: Option<Refresh>,
+}
+
+impl Chart {
+    pub fn new(kind: ChartKind) -> Self {
+        Chart {
+            id: None,
+            kind,
+            labels: Vec::new(),
+            datasets: Vec::new(),
+            format: ValueFormat::default(),
+            legend: false,
+            refresh: None,
+        }
+    }
+
+    pub fn line() -> Self {
+        Chart::new(ChartKind::Line)
+    }
+
+    pub fn area() -> Self {
+        Chart::new(ChartKind::Area)
+    }
+
+    pub fn bar() -> Self {
+        Chart::new(ChartKind::Bar)
+    }
+
+    pub fn horizontal_bar() -> Self {
+        Chart::new(ChartKind::HorizontalBar)
+    }
+
+    pub fn pie() -> Self {
+        Chart::new(ChartKind::Pie)
+    }
+
+    pub fn doughnut() -> Self {
+        Chart::new(ChartKind::Doughnut)
+    }
+
+    pub fn with_id<S: Into<Cow<'static, str>>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    fn fallback(&self) -> SvgChart {
+        SvgChart::from(self.clone())
+    }
+
+    /// Id of the canvas, which stays the same when only the values change.
+    pub fn id(&self) -> Cow<'_, str> {
+        match &self.id {
+            Some(id) => Cow::Borrowed(id),
+            None => Cow::Owned(content_id(
+                "chart",
+                std::iter::once(self.kind.chart_type())
+                    .chain(self.labels.iter().map(AsRef::as_ref))
+                    .chain(self.datasets.iter().map(|dataset| dataset.label.as_ref())),
+            )),
+        }
+    }
+
+    /// Labels of the x axis, or of the slices of pie and doughnut charts.
+    pub fn with_labels<S: Into<Cow<'static, str>>, I: IntoIterator<Item = S>>(
+        mut self,
+        labels: I,
+    ) -> Self {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_dataset(mut self, dataset: Dataset) -> Self {
+        self.datasets.push(dataset);
+        self
+    }
+
+    pub fn with_format(mut self, format: ValueFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn with_legend(mut self) -> Self {
+        self.legend = true;
+        self
+    }
+
+    /// Periodically replace the labels and values with the [`ChartData`]
+    /// returned by `url`, updating the chart in place.
+    pub fn with_refresh<S: Into<Cow<'static, str>>>(mut self, url: S, interval: Duration) -> Self {
+        self.refresh = Some(Refresh {
+            url: url.into(),
+            interval,
+        });
+        self
+    }
+
+    /// Labels and values only, as returned by the [`Chart::with_refresh`] endpoint.
+    pub fn data(&self) -> ChartData {
+        ChartData {
+            labels: self.labels.clone(),
+            values: self
+                .datasets
+                .iter()
+                .map(|dataset| dataset.values.clone())
+                .collect(),
+        }
+    }
+
+    /// Chart.js configuration, along with the value format used by `charts.js`.
+    fn config(&self) -> String {
+        let datasets: Vec<_> = self
+            .datasets
+            .iter()
+            .enumerate()
+            .map(|(index, dataset)| self.dataset_config(index, dataset))
+            .collect();
+
+        let mut options = format!(r#""legend": {{"display": {}}}"#, self.legend);
+        if self.kind == ChartKind::Doughnut {
+            options.push_str(r#", "cutoutPercentage": 80"#);
+        }
+
+        format!(
+            r#"{{"type": "{}", "data": {{"labels": {}, "datasets": [{}]}}, "options": {{{}}}, "format": {}}}"#,
+            self.kind.chart_type(),
+            json_strings(&self.labels),
+            datasets.join(", "),
+            options,
+            self.format.json()
+        )
+    }
+
+    fn dataset_config(&self, index: usize, dataset: &Dataset) -> String {
+        let label = json_string(&dataset.label);
+        let data = json_numbers(&dataset.values);
+        let color = dataset.series_color(index);
+
+        match self.kind {
+            ChartKind::Line | ChartKind::Area => format!(
+                r#"{{"label": {label}, "data": {data}, "lineTension": 0.3, "fill": {}, "backgroundColor": "{}", "borderColor": "{}", "pointRadius": 3, "pointBackgroundColor": "{2}", "pointBorderColor": "{2}", "pointHoverRadius": 3, "pointHitRadius": 10, "pointBorderWidth": 2}}"#,
+                self.kind == ChartKind::Area,
+                rgba(color, 0.05),
+                rgba(color, 1.0),
+            ),
+            ChartKind::Bar | ChartKind::HorizontalBar => format!(
+                r#"{{"label": {label}, "data": {data}, "backgroundColor": "{0}", "hoverBackgroundColor": "{0}", "borderColor": "{0}", "maxBarThickness": 25}}"#,
+                rgba(color, 1.0),
+            ),
+            ChartKind::Pie | ChartKind::Doughnut => {
+                let colors: Vec<_> = (0..dataset.values.len())
+                    .map(|slice| format!(r#""{}""#, rgba(dataset.slice_color(slice), 1.0)))
+                    .collect();
+
+                format!(
+                    r#"{{"label": {label}, "data": {data}, "backgroundColor": [{}], "hoverBorderColor": "rgba(234, 236, 244, 1)"}}"#,
+                    colors.join(", ")
+                )
+            }
+        }
+    }
+}
+
+/// Labels and values of a [`Chart`], serialized as JSON.
+///
+/// With the `axum` feature, it can be returned from the endpoint of
+/// [`Chart::with_refresh`]. Values are matched to datasets by position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartData {
+    pub labels: Vec<Cow<'static, str>>,
+    /// Values of each dataset.
+    pub values: Vec<Vec<f64>>,
+}
+
+impl Display for ChartData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let datasets: Vec<_> = self
+            .values
+            .iter()
+            .map(|values| format!(r#"{{"data": {}}}"#, json_numbers(values)))
+            .collect();
+
+        write!(
+            f,
+            r#"{{"labels": {}, "datasets": [{}]}}"#,
+            json_strings(&self.labels),
+            datasets.join(", ")
+        )
+    }
+}
+
+#[cfg(feature = "axum")]
+mod axum_chart {
+    use axum::{
+        http::header,
+        response::{IntoResponse, Response},
+    };
+
+    use super::ChartData;
+
+    impl IntoResponse for ChartData {
+        fn into_response(self) -> Response {
+            (
+                [(header::CONTENT_TYPE, "application/json")],
+                self.to_string(),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// Series colors used when none is given, in order.
+const PALETTE: [Color; 7] = [
+    Color::Primary,
+    Color::Success,
+    Color::Info,
+    Color::Warning,
+    Color::Danger,
+    Color::Secondary,
+    Color::Dark,
+];
+
+/// SB-Admin-2 theme color as CSS `rgba()`.
+pub(crate) fn rgba(color: Color, alpha: f32) -> String {
+    let (r, g, b) = match color {
+        Color::Primary => (78, 115, 223),
+        Color::Secondary => (133, 135, 150),
+        Color::Success => (28, 200, 138),
+        Color::Danger => (231, 74, 59),
+        Color::Warning => (246, 194, 62),
+        Color::Info => (54, 185, 204),
+        Color::Light => (248, 249, 252),
+        Color::Dark => (90, 92, 105),
+    };
+
+    format!("rgba({r}, {g}, {b}, {alpha})")
+}
+
+fn json_strings(values: &[Cow<'static, str>]) -> String {
+    let values: Vec<_> = values.iter().map(|value| json_string(value)).collect();
+    format!("[{}]", values.join(", "))
+}
+
+/// JSON array of the values, with `null` for those which are not finite.
+fn json_numbers(values: &[f64]) -> String {
+    let values: Vec<_> = values
+        .iter()
+        .map(|value| {
+            if value.is_finite() {
+                value.to_string()
+            } else {
+                String::from("null")
+            }
+        })
+        .collect();
+
+    format!("[{}]", values.join(", "))
+}
+
+#[cfg(test)]
+#[test]
+fn render_fallback() {
+    let chart = Chart::bar()
+        .with_labels(["Q1", "Q2"])
+        .with_dataset(Dataset::new("Revenue", [120.0, 180.5]))
+        .to_string();
+
+    assert!(chart.contains(r#"<canvas id="chart-"#));
+    assert!(chart.contains(" hidden></canvas>"));
+    assert!(chart.contains(r#"<div class="chart-fallback"><svg "#));
+    assert!(chart.contains("<title>Q2\nRevenue: 180.5</title>"));
+    assert!(crate::files::STATIC_FILES
+        .get_file("js/charts.js")
+        .is_some());
+}
+
+#[cfg(test)]
+#[test]
+fn format_values() {
+    let format = ValueFormat::new().with_prefix("$").with_decimals(2);
+
+    assert_eq!(format.format(1234567.891), "$1,234,567.89");
+    assert_eq!(format.format(-1000.0), "$-1,000.00");
+    assert_eq!(ValueFormat::new().with_suffix("%").format(12.5), "12.5%");
+    assert_eq!(ValueFormat::new().format(100.0), "100");
+}
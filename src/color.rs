@@ -1,8 +1,11 @@
 use std::fmt::Display;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 /// One of the [Bootstrap-defined colors](https://getbootstrap.com/docs/4.6/utilities/colors/).
 pub enum Color {
+    #[default]
     Primary,
     Secondary,
     Success,
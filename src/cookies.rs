@@ -0,0 +1,48 @@
+//! Reading the cookies in which the bundled scripts persist client-side
+//! state, such as [`SidebarState`](crate::SidebarState) and
+//! [`CardState`](crate::card::CardState).
+
+/// State restored from one or more cookies.
+pub(crate) trait CookieState: Default {
+    /// Apply a single cookie, ignoring unrelated ones.
+    fn apply_cookie(&mut self, name: &str, value: &str);
+}
+
+/// `(name, value)` pairs of the cookies in the contents of a `Cookie` header.
+pub(crate) fn cookies(header: &str) -> impl Iterator<Item = (&str, &str)> {
+    header
+        .split(';')
+        .filter_map(|cookie| cookie.trim().split_once('='))
+}
+
+/// Ids stored `.`-separated in a single cookie value.
+pub(crate) fn ids(value: &str) -> impl Iterator<Item = String> + '_ {
+    value
+        .split('.')
+        .filter(|id| !id.is_empty())
+        .map(ToString::to_string)
+}
+
+/// Parse the state from the contents of a `Cookie` header.
+pub(crate) fn from_header<T: CookieState>(header: &str) -> T {
+    let mut state = T::default();
+    for (name, value) in cookies(header) {
+        state.apply_cookie(name, value);
+    }
+    state
+}
+
+/// Parse the state from all `Cookie` headers of a request.
+#[cfg(feature = "axum")]
+pub(crate) fn from_headers<T: CookieState>(headers: &axum::http::HeaderMap) -> T {
+    let mut state = T::default();
+    for (name, value) in headers
+        .get_all(axum::http::header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(cookies)
+    {
+        state.apply_cookie(name, value);
+    }
+    state
+}
@@ -0,0 +1,327 @@
+//! Protection against cross-site request forgery.
+//!
+//! A [`CsrfToken`] is made current for the duration of a request, usually by
+//! the [`protect`] middleware. While set, it is rendered automatically:
+//!
+//! * as a hidden `csrf_token` input in [`Form`](crate::form::Form)s,
+//!   [`LoginForm`](crate::login::LoginForm)s, [`SignupForm`](crate::login::SignupForm)s
+//!   and [`ModalAction::Post`](crate::modal::ModalAction::Post) modals,
+//! * as `data-csrf-token` on [`LinkAction::Submit`](crate::LinkAction::Submit) links,
+//! * as an `hx-headers` value on [`HxSwap::post`](crate::htmx::HxSwap::post) requests.
+//!
+//! ```no_run
+//! # #[cfg(feature = "axum")]
+//! # fn app() -> axum::Router {
+//! use axum::{middleware, routing::get, Router};
+//! use bootstrap_dashboard::csrf;
+//!
+//! Router::new()
+//!     .route("/", get(|| async { "..." }))
+//!     .layer(middleware::from_fn(csrf::protect))
+//! # }
+//! ```
+use std::{borrow::Cow, cell::RefCell, fmt::Display};
+
+thread_local! {
+    static SYNC_CURRENT: RefCell<Option<CsrfToken>> = const { RefCell::new(None) };
+}
+
+#[cfg(feature = "axum")]
+tokio::task_local! {
+    static CURRENT: CsrfToken;
+}
+
+/// Secret token which must be submitted along with every state-changing request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsrfToken(pub Cow<'static, str>);
+
+impl CsrfToken {
+    /// Name of the form field containing the token.
+    pub const FIELD: &'static str = "csrf_token";
+    /// Name of the header containing the token, used by htmx requests.
+    pub const HEADER: &'static str = "X-CSRF-Token";
+    /// Name of the cookie storing the token between requests.
+    pub const COOKIE: &'static str = "csrf_token";
+
+    pub fn new<S: Into<Cow<'static, str>>>(token: S) -> Self {
+        CsrfToken(token.into())
+    }
+
+    /// Generate a new random token.
+    #[cfg(feature = "axum")]
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; 32];
+        getrandom::getrandom(&mut bytes).expect("failed to generate CSRF token");
+
+        CsrfToken(
+            bytes
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>()
+                .into(),
+        )
+    }
+
+    /// The token set for the current request, if any.
+    pub fn current() -> Option<CsrfToken> {
+        #[cfg(feature = "axum")]
+        if let Ok(token) = CURRENT.try_with(Clone::clone) {
+            return Some(token);
+        }
+
+        SYNC_CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Make this token current while running the given future.
+    #[cfg(feature = "axum")]
+    pub async fn scope<F: std::future::Future>(self, future: F) -> F::Output {
+        CURRENT.scope(self, future).await
+    }
+
+    /// Make this token current while running the given closure, for
+    /// rendering outside of an async runtime.
+    ///
+    /// The previous token is restored even if the closure panics.
+    pub fn sync_scope<R, F: FnOnce() -> R>(self, f: F) -> R {
+        /// Restores the previous token when dropped, including while unwinding.
+        struct Restore(Option<CsrfToken>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let previous = self.0.take();
+                SYNC_CURRENT.with(|current| *current.borrow_mut() = previous);
+            }
+        }
+
+        let _restore = Restore(SYNC_CURRENT.with(|current| current.replace(Some(self))));
+        f()
+    }
+
+    /// Compare a submitted token against this one, in constant time.
+    pub fn verify(&self, submitted: &str) -> bool {
+        let expected = self.0.as_bytes();
+        let submitted = submitted.as_bytes();
+
+        expected.len() == submitted.len()
+            && expected
+                .iter()
+                .zip(submitted)
+                .fold(0, |acc, (a, b)| acc | (a ^ b))
+                == 0
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for CsrfToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Hidden input containing the current [`CsrfToken`], rendering nothing if none is set.
+#[derive(Debug, Clone, Copy)]
+pub struct CsrfInput;
+
+impl Display for CsrfInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match CsrfToken::current() {
+            Some(token) => write!(
+                f,
+                r#"<input type="hidden" name="{}" value="{}">"#,
+                CsrfToken::FIELD,
+                askama::MarkupDisplay::new_unsafe(token.as_str(), askama::Html)
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+/// `hx-headers` attribute sending the current [`CsrfToken`], if any.
+pub(crate) fn hx_headers() -> Option<String> {
+    CsrfToken::current().map(|token| {
+        let token = token.0.replace('\\', "\\\\").replace('"', "\\\"");
+        format!(
+            " hx-headers='{{\"{}\": \"{}\"}}'",
+            CsrfToken::HEADER,
+            askama::MarkupDisplay::new_unsafe(token, askama::Html)
+        )
+    })
+}
+
+#[cfg(feature = "axum")]
+pub use axum_csrf::protect;
+
+#[cfg(feature = "axum")]
+mod axum_csrf {
+    use axum::{
+        extract::Request,
+        http::{header, HeaderValue, Method, StatusCode},
+        middleware::Next,
+        response::{IntoResponse, Response},
+    };
+
+    use super::CsrfToken;
+
+    /// Middleware making a [`CsrfToken`] current for each request, and
+    /// rejecting `POST`, `PUT`, `PATCH` and `DELETE` requests which do not
+    /// submit it either in the [`CsrfToken::HEADER`] header or the
+    /// [`CsrfToken::FIELD`] field of a url-encoded or multipart form.
+    ///
+    /// In multipart forms the field must come before any large file fields,
+    /// as rendered by [`Form`](crate::form::Form), since only the start of
+    /// the body is searched for it.
+    ///
+    /// The token is stored in the [`CsrfToken::COOKIE`] cookie, which is
+    /// set on the first response. Use it with [`axum::middleware::from_fn`].
+    pub async fn protect(request: Request, next: Next) -> Response {
+        let existing = request
+            .headers()
+            .get_all(header::COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(crate::cookies::cookies)
+            .find(|(name, _)| *name == CsrfToken::COOKIE)
+            .map(|(_, value)| value)
+            .filter(|value| !value.is_empty() && value.bytes().all(|b| b.is_ascii_hexdigit()))
+            .map(|value| CsrfToken::new(value.to_string()));
+
+        let safe = matches!(
+            *request.method(),
+            Method::GET | Method::HEAD | Method::OPTIONS | Method::TRACE
+        );
+
+        let request = if safe {
+            request
+        } else {
+            let (request, submitted) = match submitted_token(request).await {
+                Ok(submitted) => submitted,
+                Err(response) => return response,
+            };
+            let verified = match (&existing, submitted) {
+                (Some(token), Some(submitted)) => token.verify(&submitted),
+                _ => false,
+            };
+
+            if !verified {
+                return (StatusCode::FORBIDDEN, "Invalid or missing CSRF token").into_response();
+            }
+
+            request
+        };
+
+        let token = existing.clone().unwrap_or_else(CsrfToken::generate);
+        let mut response = token.clone().scope(next.run(request)).await;
+
+        if existing.is_none() {
+            if let Ok(cookie) = HeaderValue::from_str(&format!(
+                "{}={}; Path=/; SameSite=Strict; HttpOnly",
+                CsrfToken::COOKIE,
+                token
+            )) {
+                response.headers_mut().append(header::SET_COOKIE, cookie);
+            }
+        }
+
+        response
+    }
+
+    async fn submitted_token(request: Request) -> Result<(Request, Option<String>), Response> {
+        if let Some(token) = request
+            .headers()
+            .get(CsrfToken::HEADER)
+            .and_then(|value| value.to_str().ok())
+        {
+            let token = token.to_string();
+            return Ok((request, Some(token)));
+        }
+
+        crate::body::form_field(request, CsrfToken::FIELD).await
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn render_current_token() {
+    use crate::htmx::HxSwap;
+
+    assert_eq!(CsrfInput.to_string(), "");
+
+    CsrfToken::new("abc123").sync_scope(|| {
+        assert_eq!(
+            CsrfInput.to_string(),
+            r#"<input type="hidden" name="csrf_token" value="abc123">"#
+        );
+        assert!(HxSwap::post("/save")
+            .to_string()
+            .ends_with(r#" hx-headers='{"X-CSRF-Token": "abc123"}'"#));
+        assert!(!HxSwap::get("/load").to_string().contains("hx-headers"));
+    });
+
+    assert!(CsrfToken::current().is_none());
+}
+
+#[cfg(test)]
+#[test]
+fn restore_token_after_panic() {
+    let outer = CsrfToken::new("outer").sync_scope(|| {
+        let result = std::panic::catch_unwind(|| {
+            CsrfToken::new("inner").sync_scope(|| panic!("failed to render"))
+        });
+        assert!(result.is_err());
+        CsrfToken::current()
+    });
+
+    assert_eq!(outer, Some(CsrfToken::new("outer")));
+    assert_eq!(CsrfToken::current(), None);
+}
+
+#[cfg(all(test, feature = "axum"))]
+#[tokio::test]
+async fn protect_multipart_form() {
+    use axum::{
+        body::{to_bytes, Body},
+        http::{header, Request, StatusCode},
+        middleware,
+        routing::post,
+        Router,
+    };
+    use tower::ServiceExt;
+
+    let app = Router::new()
+        .route("/upload", post(|body: String| async move { body }))
+        .layer(middleware::from_fn(protect));
+
+    let body = [
+        "--XyZ\r\n",
+        "Content-Disposition: form-data; name=\"csrf_token\"\r\n\r\n",
+        "abc123\r\n",
+        "--XyZ\r\n",
+        "Content-Disposition: form-data; name=\"avatar\"; filename=\"avatar.txt\"\r\n",
+        "Content-Type: text/plain\r\n\r\n",
+        "not an image\r\n",
+        "--XyZ--\r\n",
+    ]
+    .concat();
+    let upload = |cookie: &str| {
+        Request::post("/upload")
+            .header(header::COOKIE, cookie)
+            .header(header::CONTENT_TYPE, "multipart/form-data; boundary=XyZ")
+            .body(Body::from(body.clone()))
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(upload("csrf_token=abc123"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let echoed = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(echoed, body.as_bytes());
+
+    let response = app.oneshot(upload("csrf_token=abc124")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
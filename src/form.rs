@@ -0,0 +1,686 @@
+//! Forms with typed input fields and per-field validation feedback.
+//!
+//! Forms can either be submitted as a regular `POST`, or via htmx, in which
+//! case the server responds with either the re-rendered [`Form`] containing
+//! validation feedback, or a [`Submitted`] response on success.
+//!
+//! See [`Modal::form`](crate::modal::Modal::form) for showing forms in a modal.
+use std::{borrow::Cow, fmt::Display};
+
+use askama::Template;
+
+use crate::{
+    htmx::{HxSwap, SwapTarget, Target},
+    Icon,
+};
+
+/// Type of [`Field`] input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    Text,
+    Email,
+    Password,
+    Number,
+    Textarea,
+    Hidden,
+    Date,
+    /// File upload, submitting the form as `multipart/form-data`.
+    File,
+    /// Drop-down list of [`Field::options`].
+    Select,
+    /// List of [`Field::options`] allowing several to be selected.
+    MultiSelect,
+    /// One radio button per [`Field::options`] entry.
+    Radio,
+    Checkbox,
+    /// Checkbox styled as a toggle switch.
+    Switch,
+}
+
+impl AsRef<str> for FieldKind {
+    fn as_ref(&self) -> &str {
+        match self {
+            FieldKind::Text => "text",
+            FieldKind::Email => "email",
+            FieldKind::Password => "password",
+            FieldKind::Number => "number",
+            FieldKind::Textarea => "textarea",
+            FieldKind::Hidden => "hidden",
+            FieldKind::Date => "date",
+            FieldKind::File => "file",
+            FieldKind::Select | FieldKind::MultiSelect => "select",
+            FieldKind::Radio => "radio",
+            FieldKind::Checkbox | FieldKind::Switch => "checkbox",
+        }
+    }
+}
+
+impl Display for FieldKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// Choice of a select, multi-select or radio [`Field`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldOption {
+    /// Submitted value.
+    pub value: Cow<'static, str>,
+    pub label: Cow<'static, str>,
+}
+
+impl FieldOption {
+    pub fn new<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        value: S1,
+        label: S2,
+    ) -> Self {
+        FieldOption {
+            value: value.into(),
+            label: label.into(),
+        }
+    }
+}
+
+/// Text or icon attached to the side of an input, forming an input group.
+#[derive(Debug, Clone)]
+pub enum Addon {
+    Text(Cow<'static, str>),
+    Icon(Icon),
+}
+
+impl Display for Addon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Addon::Text(text) => write!(f, "{}", crate::Text(text.clone())),
+            Addon::Icon(icon) => write!(f, r#"<i class="fas {icon} fa-fw"></i>"#),
+        }
+    }
+}
+
+impl From<&'static str> for Addon {
+    fn from(value: &'static str) -> Self {
+        Addon::Text(value.into())
+    }
+}
+
+impl From<String> for Addon {
+    fn from(value: String) -> Self {
+        Addon::Text(value.into())
+    }
+}
+
+impl From<Icon> for Addon {
+    fn from(value: Icon) -> Self {
+        Addon::Icon(value)
+    }
+}
+
+/// Single labeled input of a [`Form`].
+#[derive(Debug, Clone)]
+pub struct Field {
+    pub kind: FieldKind,
+    /// Name under which the value is submitted.
+    pub name: Cow<'static, str>,
+    pub label: Cow<'static, str>,
+    /// Current value, such as the previously submitted one.
+    ///
+    /// For checkboxes and switches, this is the value submitted when checked,
+    /// defaulting to `true`.
+    pub value: Option<Cow<'static, str>>,
+    /// Additional selected values of a [`FieldKind::MultiSelect`].
+    pub values: Vec<Cow<'static, str>>,
+    /// Whether a checkbox or switch is checked.
+    pub checked: bool,
+    /// Choices of select, multi-select and radio fields.
+    pub options: Vec<FieldOption>,
+    /// For selects, the label of an empty first choice.
+    pub placeholder: Option<Cow<'static, str>>,
+    /// Hint shown below the input.
+    pub help: Option<Cow<'static, str>>,
+    pub prepend: Option<Addon>,
+    pub append: Option<Addon>,
+    /// Accepted file types of a [`FieldKind::File`], such as `image/*`.
+    pub accept: Option<Cow<'static, str>>,
+    pub required: bool,
+    /// Validation error shown below the input, marking it invalid.
+    pub feedback: Option<Cow<'static, str>>,
+    /// Whether the input is marked as valid, unless it has [`Field::feedback`].
+    pub valid: bool,
+    /// Message shown below a valid input.
+    pub valid_feedback: Option<Cow<'static, str>>,
+}
+
+impl Field {
+    pub fn new<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        kind: FieldKind,
+        name: S1,
+        label: S2,
+    ) -> Self {
+        Field {
+            kind,
+            name: name.into(),
+            label: label.into(),
+            value: None,
+            values: Vec::new(),
+            checked: false,
+            options: Vec::new(),
+            placeholder: None,
+            help: None,
+            prepend: None,
+            append: None,
+            accept: None,
+            required: false,
+            feedback: None,
+            valid: false,
+            valid_feedback: None,
+        }
+    }
+
+    pub fn text<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        name: S1,
+        label: S2,
+    ) -> Self {
+        Field::new(FieldKind::Text, name, label)
+    }
+
+    pub fn email<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        name: S1,
+        label: S2,
+    ) -> Self {
+        Field::new(FieldKind::Email, name, label)
+    }
+
+    pub fn password<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        name: S1,
+        label: S2,
+    ) -> Self {
+        Field::new(FieldKind::Password, name, label)
+    }
+
+    pub fn number<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        name: S1,
+        label: S2,
+    ) -> Self {
+        Field::new(FieldKind::Number, name, label)
+    }
+
+    pub fn textarea<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        name: S1,
+        label: S2,
+    ) -> Self {
+        Field::new(FieldKind::Textarea, name, label)
+    }
+
+    pub fn hidden<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        name: S1,
+        value: S2,
+    ) -> Self {
+        Field::new(FieldKind::Hidden, name, "").with_value(value)
+    }
+
+    pub fn date<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        name: S1,
+        label: S2,
+    ) -> Self {
+        Field::new(FieldKind::Date, name, label)
+    }
+
+    pub fn file<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        name: S1,
+        label: S2,
+    ) -> Self {
+        Field::new(FieldKind::File, name, label)
+    }
+
+    pub fn select<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        name: S1,
+        label: S2,
+    ) -> Self {
+        Field::new(FieldKind::Select, name, label)
+    }
+
+    pub fn multiselect<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        name: S1,
+        label: S2,
+    ) -> Self {
+        Field::new(FieldKind::MultiSelect, name, label)
+    }
+
+    pub fn radio<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        name: S1,
+        label: S2,
+    ) -> Self {
+        Field::new(FieldKind::Radio, name, label)
+    }
+
+    pub fn checkbox<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        name: S1,
+        label: S2,
+    ) -> Self {
+        Field::new(FieldKind::Checkbox, name, label)
+    }
+
+    pub fn switch<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        name: S1,
+        label: S2,
+    ) -> Self {
+        Field::new(FieldKind::Switch, name, label)
+    }
+
+    pub fn with_value<S: Into<Cow<'static, str>>>(mut self, value: S) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn with_placeholder<S: Into<Cow<'static, str>>>(mut self, placeholder: S) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Select all of the given values of a [`FieldKind::MultiSelect`].
+    pub fn with_values<S: Into<Cow<'static, str>>, I: IntoIterator<Item = S>>(
+        mut self,
+        values: I,
+    ) -> Self {
+        self.values.extend(values.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn checked(mut self) -> Self {
+        self.checked = true;
+        self
+    }
+
+    pub fn with_option<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        mut self,
+        value: S1,
+        label: S2,
+    ) -> Self {
+        self.options.push(FieldOption::new(value, label));
+        self
+    }
+
+    pub fn with_options<I: IntoIterator<Item = FieldOption>>(mut self, options: I) -> Self {
+        self.options.extend(options);
+        self
+    }
+
+    pub fn with_help<S: Into<Cow<'static, str>>>(mut self, help: S) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Show text or an [`Icon`] before the input.
+    pub fn with_prepend<A: Into<Addon>>(mut self, addon: A) -> Self {
+        self.prepend = Some(addon.into());
+        self
+    }
+
+    /// Show text or an [`Icon`] after the input.
+    pub fn with_append<A: Into<Addon>>(mut self, addon: A) -> Self {
+        self.append = Some(addon.into());
+        self
+    }
+
+    pub fn with_accept<S: Into<Cow<'static, str>>>(mut self, accept: S) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    pub fn with_feedback<S: Into<Cow<'static, str>>>(mut self, feedback: S) -> Self {
+        self.feedback = Some(feedback.into());
+        self
+    }
+
+    /// Mark the input as valid, such as when re-rendering a form in which
+    /// other fields are invalid.
+    pub fn valid(mut self) -> Self {
+        self.valid = true;
+        self
+    }
+
+    pub fn with_valid_feedback<S: Into<Cow<'static, str>>>(mut self, feedback: S) -> Self {
+        self.valid = true;
+        self.valid_feedback = Some(feedback.into());
+        self
+    }
+
+    /// Whether the option with the given value is selected or checked.
+    pub fn is_selected(&self, value: &str) -> bool {
+        self.value.as_deref() == Some(value) || self.values.iter().any(|selected| selected == value)
+    }
+
+    fn validation_class(&self) -> &'static str {
+        match (&self.feedback, self.valid) {
+            (Some(_), _) => " is-invalid",
+            (None, true) => " is-valid",
+            (None, false) => "",
+        }
+    }
+
+    fn has_addons(&self) -> bool {
+        self.prepend.is_some() || self.append.is_some()
+    }
+}
+
+/// How a [`Form`] is submitted.
+#[derive(Debug, Clone)]
+pub enum FormAction {
+    /// Regular `POST` form submission, navigating to the response.
+    Post(Cow<'static, str>),
+    /// Submission via htmx.
+    Hx(HxSwap),
+}
+
+/// Form made up of [`Field`]s.
+#[derive(Debug, Clone, Template)]
+#[template(path = "form.html")]
+pub struct Form {
+    /// Element id of the form, also used for prefixing the ids of its fields.
+    pub id: Cow<'static, str>,
+    pub action: FormAction,
+    pub fields: Vec<Field>,
+    /// Error concerning the form as a whole, shown above the fields.
+    pub feedback: Option<Cow<'static, str>>,
+    /// Label of the submit button. If `None`, no button is rendered, such
+    /// as when the form is submitted from the footer of a modal.
+    pub submit: Option<Cow<'static, str>>,
+}
+
+impl Form {
+    pub fn new<S: Into<Cow<'static, str>>>(id: S, action: FormAction) -> Self {
+        Form {
+            id: id.into(),
+            action,
+            fields: Vec::new(),
+            feedback: None,
+            submit: Some("Submit".into()),
+        }
+    }
+
+    /// Form submitted as a regular `POST` request to `url`.
+    pub fn post<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(id: S1, url: S2) -> Self {
+        Form::new(id, FormAction::Post(url.into()))
+    }
+
+    /// Form submitted via htmx to `url`, replacing itself with the response.
+    ///
+    /// Respond with the re-rendered form to show validation feedback, or
+    /// with [`Submitted`] on success.
+    pub fn hx_post<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        id: S1,
+        url: S2,
+    ) -> Self {
+        Form::new(
+            id,
+            FormAction::Hx(
+                HxSwap::post(url)
+                    .with_target(Target::This)
+                    .with_swap(SwapTarget::OuterHtml),
+            ),
+        )
+    }
+
+    pub fn with_field(mut self, field: Field) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    pub fn with_feedback<S: Into<Cow<'static, str>>>(mut self, feedback: S) -> Self {
+        self.feedback = Some(feedback.into());
+        self
+    }
+
+    /// Set the validation feedback of the field with the given `name`.
+    pub fn with_field_feedback<S: Into<Cow<'static, str>>>(
+        mut self,
+        name: &str,
+        feedback: S,
+    ) -> Self {
+        if let Some(field) = self.fields.iter_mut().find(|field| field.name == name) {
+            field.feedback = Some(feedback.into());
+        }
+
+        self
+    }
+
+    /// Set the value of the field with the given `name`, such as when
+    /// re-rendering the form with the previously submitted values.
+    ///
+    /// For multi-selects, this selects the value in addition to those already selected.
+    /// For checkboxes and switches, use [`Form::with_field_checked`] instead.
+    pub fn with_field_value<S: Into<Cow<'static, str>>>(mut self, name: &str, value: S) -> Self {
+        if let Some(field) = self.fields.iter_mut().find(|field| field.name == name) {
+            match field.kind {
+                FieldKind::MultiSelect => field.values.push(value.into()),
+                _ => field.value = Some(value.into()),
+            }
+        }
+
+        self
+    }
+
+    /// Check or uncheck the checkbox or switch with the given `name`.
+    pub fn with_field_checked(mut self, name: &str, checked: bool) -> Self {
+        if let Some(field) = self.fields.iter_mut().find(|field| field.name == name) {
+            field.checked = checked;
+        }
+
+        self
+    }
+
+    /// Mark all visible fields without feedback as valid, such as when
+    /// re-rendering a form in which only some fields are invalid.
+    pub fn with_valid_fields(mut self) -> Self {
+        for field in &mut self.fields {
+            if field.feedback.is_none() && field.kind != FieldKind::Hidden {
+                field.valid = true;
+            }
+        }
+
+        self
+    }
+
+    pub fn with_submit<S: Into<Cow<'static, str>>>(mut self, label: S) -> Self {
+        self.submit = Some(label.into());
+        self
+    }
+
+    pub fn without_submit(mut self) -> Self {
+        self.submit = None;
+        self
+    }
+
+    /// Whether any of the fields, or the form itself, has validation feedback.
+    pub fn has_feedback(&self) -> bool {
+        self.feedback.is_some() || self.fields.iter().any(|field| field.feedback.is_some())
+    }
+
+    /// Whether the form contains file fields, and must be submitted as `multipart/form-data`.
+    fn is_multipart(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|field| field.kind == FieldKind::File)
+    }
+}
+
+#[cfg(feature = "axum")]
+mod axum_submitted {
+    use std::borrow::Cow;
+
+    use axum::{
+        http::{HeaderName, HeaderValue, StatusCode},
+        response::{IntoResponse, Response},
+    };
+
+    /// Response to a successful htmx [`Form`](super::Form) submission.
+    ///
+    /// Responds with `204 No Content`, leaving the form in place, and closing
+    /// it if it is shown in a [`Modal`](crate::modal::Modal). Any events added
+    /// with [`Submitted::with_trigger`] are fired using the `HX-Trigger` header,
+    /// allowing other parts of the page to refresh, such as a [`Dynamic`](crate::Dynamic)
+    /// with a trigger like `usersChanged from:body`.
+    #[derive(Debug, Clone, Default)]
+    pub struct Submitted {
+        triggers: Vec<Cow<'static, str>>,
+    }
+
+    impl Submitted {
+        pub fn new() -> Self {
+            Submitted::default()
+        }
+
+        pub fn with_trigger<S: Into<Cow<'static, str>>>(mut self, event: S) -> Self {
+            self.triggers.push(event.into());
+            self
+        }
+    }
+
+    impl IntoResponse for Submitted {
+        fn into_response(self) -> Response {
+            let mut response = StatusCode::NO_CONTENT.into_response();
+
+            if !self.triggers.is_empty() {
+                if let Ok(value) = HeaderValue::from_str(&self.triggers.join(", ")) {
+                    response
+                        .headers_mut()
+                        .insert(HeaderName::from_static("hx-trigger"), value);
+                }
+            }
+
+            response
+        }
+    }
+}
+
+#[cfg(feature = "axum")]
+pub use axum_submitted::Submitted;
+
+#[cfg(test)]
+#[test]
+fn form_field_feedback() {
+    let form = Form::hx_post("userForm", "/users")
+        .with_field(Field::email("email", "Email"))
+        .with_field_value("email", "not-an-email")
+        .with_field_feedback("email", "Invalid email address");
+
+    let html = form.to_string();
+    assert!(form.has_feedback());
+    assert!(html.contains(r#"hx-post="/users" hx-target="this" hx-swap="outerHTML""#));
+    assert!(html.contains(r#"id="userForm-email""#));
+    assert!(html.contains("is-invalid"));
+    assert!(html.contains("Invalid email address"));
+}
+
+#[cfg(test)]
+#[test]
+fn render_field_kinds() {
+    let form = Form::post("settings", "/settings")
+        .with_field(
+            Field::select("role", "Role")
+                .with_placeholder("Choose a role")
+                .with_option("admin", "Administrator")
+                .with_option("user", "User"),
+        )
+        .with_field(
+            Field::multiselect("teams", "Teams")
+                .with_option("a", "A")
+                .with_option("b", "B")
+                .with_option("c", "C")
+                .with_values(["a", "c"]),
+        )
+        .with_field(
+            Field::radio("plan", "Plan")
+                .with_option("free", "Free")
+                .with_option("pro", "Pro"),
+        )
+        .with_field(Field::switch("notify", "Notifications").checked())
+        .with_field(
+            Field::number("quota", "Quota")
+                .with_prepend(crate::icons::fa::DATABASE)
+                .with_append("GB")
+                .with_help("Storage available to the user"),
+        )
+        .with_field(Field::file("avatar", "Avatar").with_accept("image/*"))
+        .with_field_value("role", "user")
+        .with_field_value("plan", "pro")
+        .with_field_feedback("plan", "Upgrade required")
+        .with_valid_fields();
+
+    let html = form.to_string();
+    assert!(html.contains(r#"enctype="multipart/form-data""#));
+    assert!(html.contains(r#"<option value="">Choose a role</option>"#));
+    assert!(html.contains(r#"<option value="user" selected>User</option>"#));
+    assert!(html.contains(r#"<option value="a" selected>A</option>"#));
+    assert!(html.contains(r#"<option value="b">B</option>"#));
+    assert!(html.contains(r#"<option value="c" selected>C</option>"#));
+    assert!(html.contains(r#"class="form-check-input is-invalid" value="pro""#));
+    assert!(html.contains("Upgrade required"));
+    assert!(html.contains(r#"class="custom-control custom-switch""#));
+    assert!(html.contains(r#"class="custom-control-input is-valid""#));
+    assert!(html.contains(r#"<span class="input-group-text">GB</span>"#));
+    assert!(html.contains(r#"aria-describedby="settings-quota-help""#));
+    assert!(html.contains(r#"accept="image/*""#));
+}
+
+#[cfg(all(test, feature = "axum"))]
+#[tokio::test]
+async fn protected_file_upload() {
+    use axum::{
+        body::Body,
+        http::{header, Request, StatusCode},
+        middleware,
+        routing::post,
+        Router,
+    };
+    use tower::ServiceExt;
+
+    use crate::csrf::{self, CsrfToken};
+
+    let html = CsrfToken::new("abc123")
+        .scope(async {
+            Form::post("upload", "/upload")
+                .with_field(Field::file("avatar", "Avatar"))
+                .to_string()
+        })
+        .await;
+
+    assert!(html.contains(r#"method="POST" action="/upload" enctype="multipart/form-data""#));
+    let token = html
+        .find(r#"<input type="hidden" name="csrf_token" value="abc123">"#)
+        .unwrap();
+    assert!(token < html.find(r#"name="avatar""#).unwrap());
+
+    // Submitted by the browser in the order the inputs were rendered.
+    let body = [
+        "--XyZ\r\n",
+        "Content-Disposition: form-data; name=\"csrf_token\"\r\n\r\n",
+        "abc123\r\n",
+        "--XyZ\r\n",
+        "Content-Disposition: form-data; name=\"avatar\"; filename=\"avatar.png\"\r\n",
+        "Content-Type: image/png\r\n\r\n",
+        "\u{89}PNG\r\n",
+        "--XyZ--\r\n",
+    ]
+    .concat();
+
+    let response = Router::new()
+        .route("/upload", post(|| async { "Uploaded" }))
+        .layer(middleware::from_fn(csrf::protect))
+        .oneshot(
+            Request::post("/upload")
+                .header(header::COOKIE, "csrf_token=abc123")
+                .header(header::CONTENT_TYPE, "multipart/form-data; boundary=XyZ")
+                .body(Body::from(body))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
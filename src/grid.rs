@@ -1,8 +1,13 @@
-use std::{borrow::Cow, collections::HashSet, fmt::Display, hash::Hash};
+//! Bootstrap [grid system](https://getbootstrap.com/docs/4.6/layout/grid/):
+//! [`Container`]s holding [`Row`]s of [`Column`]s.
+//!
+//! Values with a limited range, such as column [`Span`]s, are checked when
+//! constructed, so invalid classes are never rendered.
+use std::{collections::BTreeMap, fmt::Display};
 
 use askama::Template;
 
-#[derive(Hash, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Breakpoint {
     #[default]
     ExtraSmall,
@@ -24,16 +29,145 @@ impl Display for Breakpoint {
     }
 }
 
-#[derive(Default, PartialEq, Eq, PartialOrd, Ord)]
+/// Number of grid columns spanned by a [`Column`], between 1 and 12.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Span(u8);
+
+impl Span {
+    /// # Panics
+    ///
+    /// If `span` is not within `1..=12`. When used in a `const` context,
+    /// this is a compile-time error instead.
+    pub const fn new(span: u8) -> Span {
+        assert!(span >= 1 && span <= 12, "column span must be within 1..=12");
+        Span(span)
+    }
+
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+}
+
+impl TryFrom<u8> for Span {
+    type Error = GridError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1..=12 => Ok(Span(value)),
+            _ => Err(GridError::Span(value)),
+        }
+    }
+}
+
+/// Number of grid columns a [`Column`] is moved to the right, between 0 and 11.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Offset(u8);
+
+impl Offset {
+    /// # Panics
+    ///
+    /// If `offset` is not within `0..=11`.
+    pub const fn new(offset: u8) -> Offset {
+        assert!(offset <= 11, "column offset must be within 0..=11");
+        Offset(offset)
+    }
+
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+}
+
+impl TryFrom<u8> for Offset {
+    type Error = GridError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0..=11 => Ok(Offset(value)),
+            _ => Err(GridError::Offset(value)),
+        }
+    }
+}
+
+/// Number of columns per line of a [`Row`], between 1 and 6.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RowColumns(u8);
+
+impl RowColumns {
+    /// # Panics
+    ///
+    /// If `columns` is not within `1..=6`.
+    pub const fn new(columns: u8) -> RowColumns {
+        assert!(
+            columns >= 1 && columns <= 6,
+            "row columns must be within 1..=6"
+        );
+        RowColumns(columns)
+    }
+
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+}
+
+impl TryFrom<u8> for RowColumns {
+    type Error = GridError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1..=6 => Ok(RowColumns(value)),
+            _ => Err(GridError::RowColumns(value)),
+        }
+    }
+}
+
+/// Value outside of the range supported by Bootstrap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridError {
+    Span(u8),
+    Offset(u8),
+    Order(u8),
+    RowColumns(u8),
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Span(value) => write!(f, "column span {value} is not within 1..=12"),
+            GridError::Offset(value) => write!(f, "column offset {value} is not within 0..=11"),
+            GridError::Order(value) => write!(f, "column order {value} is not within 0..=12"),
+            GridError::RowColumns(value) => write!(f, "row columns {value} is not within 1..=6"),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// Width of a [`Column`] at a [`Breakpoint`].
+///
+/// [`ColumnWidth::Sized`] holds a checked [`Span`] rather than a raw `u8`,
+/// so out-of-range widths can no longer be constructed. Use
+/// `ColumnWidth::Sized(Span::new(4))`, or `ColumnWidth::try_from(4)` for
+/// widths only known at runtime.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ColumnWidth {
+    /// Equal share of the remaining width.
     #[default]
     None,
-    Sized(u8),
+    Sized(Span),
+    /// Width of the content.
     Auto,
 }
 
-impl From<u8> for ColumnWidth {
-    fn from(value: u8) -> Self {
+impl TryFrom<u8> for ColumnWidth {
+    type Error = GridError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Span::try_from(value).map(ColumnWidth::Sized)
+    }
+}
+
+impl From<Span> for ColumnWidth {
+    fn from(value: Span) -> Self {
         ColumnWidth::Sized(value)
     }
 }
@@ -42,24 +176,18 @@ impl Display for ColumnWidth {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ColumnWidth::None => Ok(()),
-            ColumnWidth::Sized(n) => write!(f, "-{n}"),
+            ColumnWidth::Sized(n) => write!(f, "-{}", n.get()),
             ColumnWidth::Auto => f.write_str("-auto"),
         }
     }
 }
 
-#[derive(Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ColumnSize {
     pub breakpoint: Breakpoint,
     pub width: ColumnWidth,
 }
 
-impl Hash for ColumnSize {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.breakpoint.hash(state);
-    }
-}
-
 impl ColumnSize {
     pub fn new(breakpoint: Breakpoint, width: ColumnWidth) -> Self {
         ColumnSize { breakpoint, width }
@@ -72,7 +200,7 @@ impl ColumnSize {
         }
     }
 
-    pub fn with_width(self, width: u8) -> Self {
+    pub fn with_width(self, width: Span) -> Self {
         ColumnSize {
             breakpoint: self.breakpoint,
             width: ColumnWidth::Sized(width),
@@ -93,50 +221,302 @@ impl Display for ColumnSize {
     }
 }
 
-#[derive(Template)]
-#[template(path = "column.html")]
+/// Visual order of a [`Column`] within its [`Row`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Order(i8);
+
+impl Order {
+    pub const FIRST: Order = Order(-1);
+    pub const LAST: Order = Order(13);
+
+    /// # Panics
+    ///
+    /// If `position` is not within `0..=12`.
+    pub const fn new(position: u8) -> Order {
+        assert!(position <= 12, "column order must be within 0..=12");
+        Order(position as i8)
+    }
+}
+
+impl TryFrom<u8> for Order {
+    type Error = GridError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0..=12 => Ok(Order(value as i8)),
+            _ => Err(GridError::Order(value)),
+        }
+    }
+}
+
+impl Display for Order {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Order::FIRST => f.write_str("first"),
+            Order::LAST => f.write_str("last"),
+            Order(position) => write!(f, "{position}"),
+        }
+    }
+}
+
+/// Whether an element is shown from a [`Breakpoint`] upwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visibility {
+    Visible,
+    Hidden,
+}
+
+impl AsRef<str> for Visibility {
+    fn as_ref(&self) -> &str {
+        match self {
+            Visibility::Visible => "block",
+            Visibility::Hidden => "none",
+        }
+    }
+}
+
+impl Display for Visibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// Vertical alignment of the columns in a [`Row`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Start,
+    Center,
+    End,
+    Baseline,
+    Stretch,
+}
+
+impl AsRef<str> for Align {
+    fn as_ref(&self) -> &str {
+        match self {
+            Align::Start => "start",
+            Align::Center => "center",
+            Align::End => "end",
+            Align::Baseline => "baseline",
+            Align::Stretch => "stretch",
+        }
+    }
+}
+
+impl Display for Align {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// Horizontal distribution of the columns in a [`Row`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Justify {
+    Start,
+    Center,
+    End,
+    Between,
+    Around,
+}
+
+impl AsRef<str> for Justify {
+    fn as_ref(&self) -> &str {
+        match self {
+            Justify::Start => "start",
+            Justify::Center => "center",
+            Justify::End => "end",
+            Justify::Between => "between",
+            Justify::Around => "around",
+        }
+    }
+}
+
+impl Display for Justify {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// Column of a [`Row`], containing any [`Display`] content.
+///
+/// Any such content converts into a column, so it can be passed to
+/// [`Row::with_column`] directly. For that reason a column is only ever
+/// rendered as part of its row, and does not implement [`Display`] itself.
 pub struct Column {
-    sizes: HashSet<ColumnSize>,
-    content: Cow<'static, str>,
+    sizes: BTreeMap<Breakpoint, ColumnWidth>,
+    offsets: BTreeMap<Breakpoint, Offset>,
+    orders: BTreeMap<Breakpoint, Order>,
+    visibility: BTreeMap<Breakpoint, Visibility>,
+    content: Box<dyn Display + Send + Sync>,
 }
 
 impl Column {
-    pub fn new<Content: Into<Cow<'static, str>>>(content: Content) -> Column {
+    /// Column containing any component or template, such as a [`Card`](crate::card::Card).
+    pub fn new<Content: Display + Send + Sync + 'static>(content: Content) -> Column {
         Column {
-            sizes: HashSet::new(),
-            content: content.into(),
+            sizes: BTreeMap::new(),
+            offsets: BTreeMap::new(),
+            orders: BTreeMap::new(),
+            visibility: BTreeMap::new(),
+            content: Box::new(content),
         }
     }
 
+    /// Width at `breakpoint`, either a [`ColumnWidth`] or a [`Span`].
     pub fn with_size<Width: Into<ColumnWidth>>(
         mut self,
         breakpoint: Breakpoint,
         width: Width,
     ) -> Self {
-        self.sizes.insert(ColumnSize::new(breakpoint, width.into()));
+        self.sizes.insert(breakpoint, width.into());
         self
     }
+
+    /// Width at `breakpoint` only known at runtime, such as from a setting.
+    pub fn try_with_size(self, breakpoint: Breakpoint, width: u8) -> Result<Self, GridError> {
+        Ok(self.with_size(breakpoint, Span::try_from(width)?))
+    }
+
+    pub fn with_offset(mut self, breakpoint: Breakpoint, offset: Offset) -> Self {
+        self.offsets.insert(breakpoint, offset);
+        self
+    }
+
+    pub fn with_order(mut self, breakpoint: Breakpoint, order: Order) -> Self {
+        self.orders.insert(breakpoint, order);
+        self
+    }
+
+    /// Show or hide the column from `breakpoint` upwards.
+    pub fn with_visibility(mut self, breakpoint: Breakpoint, visibility: Visibility) -> Self {
+        self.visibility.insert(breakpoint, visibility);
+        self
+    }
+
+    /// Only show the column from `breakpoint` upwards.
+    pub fn visible_from(self, breakpoint: Breakpoint) -> Self {
+        self.with_visibility(Breakpoint::ExtraSmall, Visibility::Hidden)
+            .with_visibility(breakpoint, Visibility::Visible)
+    }
+
+    /// Hide the column from `breakpoint` upwards.
+    pub fn hidden_from(self, breakpoint: Breakpoint) -> Self {
+        self.with_visibility(breakpoint, Visibility::Hidden)
+    }
+
+    fn template(&self) -> ColumnTemplate<'_> {
+        ColumnTemplate { column: self }
+    }
+
+    fn classes(&self) -> String {
+        let mut classes = Vec::new();
+
+        if self.sizes.is_empty() {
+            classes.push("col".to_string());
+        }
+
+        for (breakpoint, width) in &self.sizes {
+            classes.push(ColumnSize::new(*breakpoint, *width).to_string());
+        }
+
+        for (breakpoint, offset) in &self.offsets {
+            classes.push(format!("offset{breakpoint}-{}", offset.get()));
+        }
+
+        for (breakpoint, order) in &self.orders {
+            classes.push(format!("order{breakpoint}-{order}"));
+        }
+
+        for (breakpoint, visibility) in &self.visibility {
+            classes.push(format!("d{breakpoint}-{visibility}"));
+        }
+
+        classes.join(" ")
+    }
 }
 
-impl<T: Into<Cow<'static, str>>> From<T> for Column {
-    fn from(value: T) -> Self {
+impl<Content: Display + Send + Sync + 'static> From<Content> for Column {
+    fn from(value: Content) -> Self {
         Column::new(value)
     }
 }
 
+#[derive(Template)]
+#[template(path = "column.html")]
+struct ColumnTemplate<'a> {
+    column: &'a Column,
+}
+
 #[derive(Template)]
 #[template(path = "row.html")]
-pub struct Row(Vec<Column>);
+pub struct Row {
+    columns: Vec<Column>,
+    gutters: bool,
+    row_columns: BTreeMap<Breakpoint, RowColumns>,
+    align: BTreeMap<Breakpoint, Align>,
+    justify: BTreeMap<Breakpoint, Justify>,
+}
 
 impl Row {
     pub fn new() -> Self {
-        Row(Vec::new())
+        Row {
+            columns: Vec::new(),
+            gutters: true,
+            row_columns: BTreeMap::new(),
+            align: BTreeMap::new(),
+            justify: BTreeMap::new(),
+        }
     }
 
     pub fn with_column<C: Into<Column>>(mut self, column: C) -> Self {
-        self.0.push(column.into());
+        self.columns.push(column.into());
+        self
+    }
+
+    /// Remove the horizontal padding between columns.
+    pub fn without_gutters(mut self) -> Self {
+        self.gutters = false;
+        self
+    }
+
+    /// Give every column the same width, fitting `columns` per line from
+    /// `breakpoint` upwards.
+    pub fn with_row_columns(mut self, breakpoint: Breakpoint, columns: RowColumns) -> Self {
+        self.row_columns.insert(breakpoint, columns);
+        self
+    }
+
+    pub fn with_align(mut self, breakpoint: Breakpoint, align: Align) -> Self {
+        self.align.insert(breakpoint, align);
+        self
+    }
+
+    pub fn with_justify(mut self, breakpoint: Breakpoint, justify: Justify) -> Self {
+        self.justify.insert(breakpoint, justify);
         self
     }
+
+    fn classes(&self) -> String {
+        let mut classes = vec!["row".to_string()];
+
+        if !self.gutters {
+            classes.push("no-gutters".to_string());
+        }
+
+        for (breakpoint, columns) in &self.row_columns {
+            classes.push(format!("row-cols{breakpoint}-{}", columns.get()));
+        }
+
+        for (breakpoint, align) in &self.align {
+            classes.push(format!("align-items{breakpoint}-{align}"));
+        }
+
+        for (breakpoint, justify) in &self.justify {
+            classes.push(format!("justify-content{breakpoint}-{justify}"));
+        }
+
+        classes.join(" ")
+    }
 }
 
 impl Default for Row {
@@ -144,3 +524,257 @@ impl Default for Row {
         Self::new()
     }
 }
+
+/// Width of a [`Container`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ContainerKind {
+    /// Fixed width at each breakpoint.
+    #[default]
+    Fixed,
+    /// Full width at every breakpoint.
+    Fluid,
+    /// Full width until the breakpoint, fixed width from then on.
+    Responsive(Breakpoint),
+}
+
+impl Display for ContainerKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContainerKind::Fixed | ContainerKind::Responsive(Breakpoint::ExtraSmall) => {
+                f.write_str("container")
+            }
+            ContainerKind::Fluid => f.write_str("container-fluid"),
+            ContainerKind::Responsive(breakpoint) => write!(f, "container{breakpoint}"),
+        }
+    }
+}
+
+#[derive(Template)]
+#[template(
+    ext = "html",
+    source = r#"
+<div class="{{ kind }}">
+    {% for row in rows %}
+    {{ row|safe }}
+    {% endfor %}
+</div>
+"#
+)]
+pub struct Container {
+    pub kind: ContainerKind,
+    rows: Vec<Row>,
+}
+
+impl Container {
+    pub fn new(kind: ContainerKind) -> Self {
+        Container {
+            kind,
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn fixed() -> Self {
+        Container::new(ContainerKind::Fixed)
+    }
+
+    pub fn fluid() -> Self {
+        Container::new(ContainerKind::Fluid)
+    }
+
+    pub fn with_row(mut self, row: Row) -> Self {
+        self.rows.push(row);
+        self
+    }
+}
+
+/// Sequence of [`Row`]s, as built by the [`grid!`](crate::grid!) macro.
+#[derive(Template)]
+#[template(
+    ext = "html",
+    source = r#"
+{% for row in self.0 %}
+{{ row|safe }}
+{% endfor %}
+"#
+)]
+pub struct Layout(pub Vec<Row>);
+
+/// Build a [`Layout`] of [`Row`]s and [`Column`]s.
+///
+/// Each `row` contains `col`s with optional sizes per breakpoint
+/// (`xs`, `sm`, `md`, `lg` or `xl`), either a width between 1 and 12 or
+/// `auto`, and optional offsets such as `offset md = 2`. The content of a
+/// column can be any [`Display`] value.
+///
+/// ```
+/// use bootstrap_dashboard::{card::Card, grid};
+///
+/// let layout = grid! {
+///     row {
+///         col(xl = 3, md = 6) => Card::new("First"),
+///         col(xl = 3, md = 6) => Card::new("Second"),
+///         col(md = auto, offset xl = 2) => "Third",
+///     }
+///     row {
+///         col => Card::new("Full width"),
+///     }
+/// };
+///
+/// assert_eq!(layout.0.len(), 2);
+/// ```
+///
+/// Widths outside of Bootstrap's range are rejected at compile time:
+///
+/// ```compile_fail
+/// use bootstrap_dashboard::grid;
+///
+/// let layout = grid! {
+///     row {
+///         col(md = 13) => "Too wide",
+///     }
+/// };
+/// ```
+#[macro_export]
+macro_rules! grid {
+    ($(row { $($columns:tt)* })*) => {
+        $crate::grid::Layout(vec![$($crate::grid!(@row $($columns)*)),*])
+    };
+    (@row $(col $(($($options:tt)*))? => $content:expr),* $(,)?) => {
+        $crate::grid::Row::new()
+            $(.with_column($crate::grid!(
+                @options $crate::grid::Column::new($content); $($($options)*)?
+            )))*
+    };
+    (@options $column:expr;) => {
+        $column
+    };
+    (@options $column:expr; offset $breakpoint:ident = $offset:literal $(, $($rest:tt)*)?) => {
+        $crate::grid!(
+            @options $column.with_offset($crate::grid!(@breakpoint $breakpoint), {
+                const OFFSET: $crate::grid::Offset = $crate::grid::Offset::new($offset);
+                OFFSET
+            });
+            $($($rest)*)?
+        )
+    };
+    (@options $column:expr; $breakpoint:ident = auto $(, $($rest:tt)*)?) => {
+        $crate::grid!(
+            @options $column.with_size(
+                $crate::grid!(@breakpoint $breakpoint),
+                $crate::grid::ColumnWidth::Auto,
+            );
+            $($($rest)*)?
+        )
+    };
+    (@options $column:expr; $breakpoint:ident = $width:literal $(, $($rest:tt)*)?) => {
+        $crate::grid!(
+            @options $column.with_size($crate::grid!(@breakpoint $breakpoint), {
+                const SPAN: $crate::grid::Span = $crate::grid::Span::new($width);
+                SPAN
+            });
+            $($($rest)*)?
+        )
+    };
+    (@breakpoint xs) => {
+        $crate::grid::Breakpoint::ExtraSmall
+    };
+    (@breakpoint sm) => {
+        $crate::grid::Breakpoint::Small
+    };
+    (@breakpoint md) => {
+        $crate::grid::Breakpoint::Medium
+    };
+    (@breakpoint lg) => {
+        $crate::grid::Breakpoint::Large
+    };
+    (@breakpoint xl) => {
+        $crate::grid::Breakpoint::ExtraLarge
+    };
+}
+
+#[cfg(test)]
+#[test]
+fn heterogeneous_columns() {
+    use crate::{card::Card, chart::Chart, form::Form, svg::SvgChart, Text};
+
+    let rendered = Row::new()
+        .with_column(Card::new("Card body"))
+        .with_column(Text::new("<escaped>"))
+        .with_column(Column::new(42).with_size(Breakpoint::Medium, Span::new(6)))
+        .with_column(Chart::bar().with_id("signups"))
+        .with_column(SvgChart::line())
+        .with_column(Form::post("search", "/search"))
+        .to_string();
+
+    assert!(rendered.contains("Card body"));
+    assert!(rendered.contains("&lt;escaped&gt;"));
+    assert!(rendered.contains(r#"<div class="col-md-6 mb-4">"#));
+    assert!(rendered.contains(r#"<canvas id="signups""#));
+    assert!(rendered.contains("<svg"));
+    assert!(rendered.contains(r#"<form id="search""#));
+}
+
+#[cfg(test)]
+#[test]
+fn grid_classes() {
+    let rendered = Container::fluid()
+        .with_row(
+            Row::new()
+                .without_gutters()
+                .with_row_columns(Breakpoint::Medium, RowColumns::new(3))
+                .with_justify(Breakpoint::ExtraSmall, Justify::Between)
+                .with_column(
+                    Column::new("Sidebar")
+                        .with_size(Breakpoint::Large, Span::new(4))
+                        .with_size(Breakpoint::ExtraSmall, ColumnWidth::Auto)
+                        .with_offset(Breakpoint::Large, Offset::new(1))
+                        .with_order(Breakpoint::Medium, Order::LAST)
+                        .visible_from(Breakpoint::Large),
+                ),
+        )
+        .to_string();
+
+    assert!(rendered.contains(r#"<div class="container-fluid">"#));
+    assert!(rendered.contains(r#"class="row no-gutters row-cols-md-3 justify-content-between""#));
+    assert!(rendered
+        .contains(r#"class="col-auto col-lg-4 offset-lg-1 order-md-last d-none d-lg-block mb-4""#));
+    assert_eq!(Span::try_from(13), Err(GridError::Span(13)));
+    assert_eq!(ColumnWidth::try_from(0), Err(GridError::Span(0)));
+    assert_eq!(
+        ColumnWidth::try_from(12),
+        Ok(ColumnWidth::Sized(Span::new(12)))
+    );
+    assert_eq!(
+        Column::new("Wide")
+            .try_with_size(Breakpoint::Large, 13)
+            .err(),
+        Some(GridError::Span(13))
+    );
+    assert_eq!(
+        ColumnSize::default()
+            .with_breakpoint(Breakpoint::Small)
+            .with_width(Span::new(3))
+            .to_string(),
+        "col-sm-3"
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn grid_macro() {
+    let layout = crate::grid! {
+        row {
+            col(xl = 3, md = 6) => "Stat",
+            col(lg = auto, offset lg = 1) => crate::Text::new("Auto"),
+        }
+        row {
+            col => "Plain",
+        }
+    };
+
+    let rendered = layout.to_string();
+    assert_eq!(layout.0.len(), 2);
+    assert!(rendered.contains(r#"<div class="col-md-6 col-xl-3 mb-4">"#));
+    assert!(rendered.contains(r#"<div class="col-lg-auto offset-lg-1 mb-4">"#));
+    assert!(rendered.contains(r#"<div class="col mb-4">"#));
+}
@@ -59,6 +59,13 @@ pub struct HxSwap {
     pub target: Option<Target>,
     pub triggers: Vec<Trigger>,
     pub swap: Option<Swap>,
+    /// CSS selector of the part of the response to swap in.
+    pub select: Option<Cow<'static, str>>,
+    /// CSS selectors of additional parts of the response to swap in out-of-band,
+    /// replacing the elements with the same id.
+    pub select_oob: Vec<Cow<'static, str>>,
+    /// CSS selector of additional inputs whose values are sent with the request.
+    pub include: Option<Cow<'static, str>>,
 }
 
 #[derive(Debug, Clone)]
@@ -83,6 +90,9 @@ impl HxSwap {
             target: None,
             triggers: vec![],
             swap: None,
+            select: None,
+            select_oob: vec![],
+            include: None,
         }
     }
 
@@ -92,6 +102,9 @@ impl HxSwap {
             target: None,
             triggers: vec![],
             swap: None,
+            select: None,
+            select_oob: vec![],
+            include: None,
         }
     }
 
@@ -109,6 +122,21 @@ impl HxSwap {
         self.swap.replace(swap.into());
         self
     }
+
+    pub fn with_select<T: Into<Cow<'static, str>>>(mut self, selector: T) -> Self {
+        self.select.replace(selector.into());
+        self
+    }
+
+    pub fn with_select_oob<T: Into<Cow<'static, str>>>(mut self, selector: T) -> Self {
+        self.select_oob.push(selector.into());
+        self
+    }
+
+    pub fn with_include<T: Into<Cow<'static, str>>>(mut self, selector: T) -> Self {
+        self.include.replace(selector.into());
+        self
+    }
 }
 
 impl Display for HxSwap {
@@ -132,7 +160,25 @@ impl Display for HxSwap {
         }
 
         if let Some(swap) = &self.swap {
-            write!(f, "{}", swap)?;
+            write!(f, " {}", swap)?;
+        }
+
+        if let Some(select) = &self.select {
+            write!(f, r#" hx-select="{select}""#)?;
+        }
+
+        if !self.select_oob.is_empty() {
+            write!(f, r#" hx-select-oob="{}""#, self.select_oob.join(","))?;
+        }
+
+        if let Some(include) = &self.include {
+            write!(f, r#" hx-include="{include}""#)?;
+        }
+
+        if let Request::Post(_) = self.url {
+            if let Some(headers) = crate::csrf::hx_headers() {
+                f.write_str(&headers)?;
+            }
         }
 
         Ok(())
@@ -168,6 +214,8 @@ pub enum TriggerEvent {
     KeyUp,
     Revealed,
     Every(Duration),
+    /// Custom event, such as one fired by the `HX-Trigger` response header.
+    Custom(&'static str),
 }
 
 impl TriggerEvent {
@@ -187,8 +235,9 @@ impl Display for TriggerEvent {
             TriggerEvent::Click => f.write_str("click"),
             TriggerEvent::MouseEnter => f.write_str("mouseenter"),
             TriggerEvent::KeyUp => f.write_str("keyup"),
-            TriggerEvent::Revealed => f.write_str("revelead"),
+            TriggerEvent::Revealed => f.write_str("revealed"),
             TriggerEvent::Every(timing) => write!(f, "every {timing:?}"),
+            TriggerEvent::Custom(event) => f.write_str(event),
         }
     }
 }
@@ -399,10 +448,10 @@ impl Display for Target {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Target::This => f.write_str("this"),
-            Target::Closest(value) => write!(f, "closest:{value}"),
-            Target::Find(value) => write!(f, "find:{value}"),
-            Target::Next(value) => write!(f, "next:{value}"),
-            Target::Previous(value) => write!(f, "previous:{value}"),
+            Target::Closest(value) => write!(f, "closest {value}"),
+            Target::Find(value) => write!(f, "find {value}"),
+            Target::Next(value) => write!(f, "next {value}"),
+            Target::Previous(value) => write!(f, "previous {value}"),
             Target::Specific(value) => f.write_str(value),
         }
     }
@@ -445,3 +494,13 @@ pub fn hx_construction() {
 
     println!("{}", props);
 }
+
+#[cfg(test)]
+#[test]
+fn relative_targets() {
+    assert_eq!(Target::Closest("tr".into()).to_string(), "closest tr");
+    assert_eq!(
+        Target::Next("[data-lazy-body]".into()).to_string(),
+        "next [data-lazy-body]"
+    );
+}
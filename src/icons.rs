@@ -12,12 +12,54 @@ impl Icon {
     }
 }
 
+impl Icon {
+    /// Font-Awesome name of the icon, without the `fa-` class prefix.
+    pub fn name(&self) -> &str {
+        self.0.trim_start_matches("fa-")
+    }
+}
+
 impl Display for Icon {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(self.0.as_ref())
     }
 }
 
+/// Icons are (de)serialized by their Font-Awesome name, such as `"cogs"`.
+///
+/// The `fa-` class prefix is optional when deserializing.
+#[cfg(feature = "serde")]
+mod serde_icon {
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Icon;
+
+    impl Serialize for Icon {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.name())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Icon {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let name = String::deserialize(deserializer)?;
+            let name = name.trim_start_matches("fa-");
+
+            if name.is_empty()
+                || !name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+            {
+                return Err(D::Error::custom(format!(
+                    "invalid Font-Awesome icon name \"{name}\""
+                )));
+            }
+
+            Ok(Icon(format!("fa-{name}").into()))
+        }
+    }
+}
+
 /// Statically typed font-awesome icons.
 pub mod fa {
     use super::Icon;
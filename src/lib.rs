@@ -6,33 +6,51 @@
 use std::{borrow::Cow, fmt::Display};
 
 pub use askama;
+// Lets derive macros refer to `::bootstrap_dashboard` from within this crate.
+extern crate self as bootstrap_dashboard;
 use askama::Template;
 
 mod alerts;
+#[cfg(feature = "axum")]
+mod body;
+mod breadcrumbs;
 pub mod card;
+pub mod chart;
 mod color;
+mod cookies;
+pub mod csrf;
 mod error;
 pub mod favicons;
 pub mod files;
+pub mod form;
 pub mod grid;
 pub mod htmx;
 pub mod icons;
 mod links;
 pub mod login;
+mod markup;
 pub mod modal;
 mod page_header;
+pub mod route;
 mod sidebar;
+pub mod svg;
+pub mod table;
 mod userinfo;
 
 pub use alerts::*;
+pub use breadcrumbs::{Breadcrumb, Breadcrumbs};
 pub use color::*;
 pub use error::Error;
 use favicons::FavIcons;
 pub use htmx::Dynamic;
 pub use icons::Icon;
-pub use links::{IconLink, LinkAction, NavLink, PlainLink};
-use modal::Modal;
+#[cfg(feature = "axum")]
+pub use links::method_override;
+pub use links::{IconLink, LinkAction, NavLink, PlainLink, SubmitMethod};
+pub use markup::{Markup, Text};
+use modal::{Modal, ModalContent};
 pub use page_header::PageHeader;
+pub use route::Route;
 pub use sidebar::*;
 pub use userinfo::*;
 
@@ -66,7 +84,8 @@ pub struct Page<Content: Display = &'static str> {
     /// of how this could be done using [axum](https://github.com/tokio-rs/axum)
     pub static_path: Cow<'static, str>,
     pub favicons: Option<FavIcons>,
-    pub modals: Vec<Modal>,
+    /// Rendered [`Modal`]s, see [`Page::with_modal`].
+    pub modals: Vec<Box<dyn Display + Send + Sync>>,
     pub content: Content,
 }
 
@@ -101,8 +120,11 @@ impl<Content: Display> Page<Content> {
         self
     }
 
-    pub fn with_modal(mut self, modal: Modal) -> Self {
-        self.modals.push(modal);
+    pub fn with_modal<Body: ModalContent + Send + Sync + 'static>(
+        mut self,
+        modal: Modal<Body>,
+    ) -> Self {
+        self.modals.push(Box::new(modal));
         self
     }
 }
@@ -148,6 +170,14 @@ impl<Content: Display> Dashboard<Content> {
         self
     }
 
+    /// Apply the persisted [`SidebarState`] to the dashboard's [`Sidebar`].
+    ///
+    /// Has no effect if no sidebar has been set yet.
+    pub fn with_sidebar_state(mut self, state: &SidebarState) -> Self {
+        self.sidebar = self.sidebar.map(|sidebar| sidebar.with_state(state));
+        self
+    }
+
     pub fn with_copyright<S: Into<Cow<'static, str>>>(mut self, copyright: S) -> Self {
         self.copyright = Some(copyright.into());
         self
@@ -168,6 +198,34 @@ impl<Content: Display> Dashboard<Content> {
         self
     }
 
+    /// Show the [`Sidebar::breadcrumbs`] trail of the active link in the
+    /// page header, creating a page header from the active link's label if
+    /// none has been set yet.
+    ///
+    /// Has no effect if there is no sidebar, or it has no active link.
+    pub fn with_sidebar_breadcrumbs(mut self) -> Self {
+        if let Some(breadcrumbs) = self.sidebar.as_ref().and_then(Sidebar::breadcrumbs) {
+            self.page_header = Some(match self.page_header {
+                Some(page_header) => page_header.with_breadcrumbs(breadcrumbs),
+                None => breadcrumbs.into(),
+            });
+        }
+
+        self
+    }
+
+    fn topbar_page_header(&self) -> Option<&PageHeader> {
+        self.page_header
+            .as_ref()
+            .filter(|page_header| page_header.is_in_topbar())
+    }
+
+    fn content_page_header(&self) -> Option<&PageHeader> {
+        self.page_header
+            .as_ref()
+            .filter(|page_header| !page_header.is_in_topbar())
+    }
+
     pub fn replace_content<NewContent: Display>(
         self,
         content: NewContent,
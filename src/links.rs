@@ -1,16 +1,92 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, fmt::Display};
 
-use askama::Template;
+use askama::{Html, MarkupDisplay, Template};
 
-use crate::{modal::Modal, Icon};
+use crate::{
+    htmx::HxSwap,
+    modal::{Modal, ModalContent},
+    route::Route,
+    Icon,
+};
 
 /// Action to take on link press.
 ///
 /// Can either act as a regular link, sending the user to a new path,
 /// or toggle a modal, such as a logout pop-up window warning or similar.
+///
+/// When (de)serialized as part of a link, the action is flattened into the
+/// link itself, i.e. `{ label = "Home", href = "/" }`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum LinkAction {
     Href(Cow<'static, str>),
     ToggleModal(Cow<'static, str>),
+    /// Link to another site, opened in a new tab.
+    External(Cow<'static, str>),
+    /// Download the linked file, optionally under a different filename.
+    Download {
+        url: Cow<'static, str>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        filename: Option<Cow<'static, str>>,
+    },
+    /// Submit a form to the url, such as for logging out or deleting a resource.
+    ///
+    /// Since HTML forms only support `GET` and `POST`, other methods are
+    /// submitted as a `POST` with a [`SubmitMethod::FIELD`] field containing
+    /// the actual method, which [`method_override`](crate::method_override)
+    /// turns back into the actual method.
+    Submit {
+        method: SubmitMethod,
+        url: Cow<'static, str>,
+        /// Anti-CSRF token submitted along with the form.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        csrf_token: Option<Cow<'static, str>>,
+    },
+    /// Fetch a [`Modal`] from the url and open it, showing a spinner while
+    /// it is loading.
+    ///
+    /// The response should be a rendered [`Modal`], which is shown in a container
+    /// shared by all lazily loaded modals, so its id is irrelevant.
+    LazyModal(Cow<'static, str>),
+    /// Issue an htmx request instead of navigating.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Hx(HxSwap),
+}
+
+/// HTTP method used by [`LinkAction::Submit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum SubmitMethod {
+    Post,
+    Delete,
+}
+
+impl SubmitMethod {
+    /// Name of the form field containing the method of non-`POST` submissions.
+    pub const FIELD: &'static str = "_method";
+}
+
+impl AsRef<str> for SubmitMethod {
+    fn as_ref(&self) -> &str {
+        match self {
+            SubmitMethod::Post => "post",
+            SubmitMethod::Delete => "delete",
+        }
+    }
+}
+
+impl Display for SubmitMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
 }
 
 impl LinkAction {
@@ -18,23 +94,88 @@ impl LinkAction {
         LinkAction::Href(url.into())
     }
 
+    /// Link to a typed [`Route`] of your application.
+    pub fn route<R: Route>(route: &R) -> Self {
+        LinkAction::Href(route.url().into())
+    }
+
     pub fn modal_name<S: Into<Cow<'static, str>>>(name: S) -> Self {
         LinkAction::ToggleModal(name.into())
     }
 
-    pub fn modal(modal: &Modal) -> Self {
-        LinkAction::ToggleModal(modal.id.clone().into())
+    pub fn modal<Content: ModalContent>(modal: &Modal<Content>) -> Self {
+        LinkAction::ToggleModal(modal.id.clone())
+    }
+
+    pub fn lazy_modal<S: Into<Cow<'static, str>>>(url: S) -> Self {
+        LinkAction::LazyModal(url.into())
+    }
+
+    pub fn external<S: Into<Cow<'static, str>>>(url: S) -> Self {
+        LinkAction::External(url.into())
+    }
+
+    pub fn download<S: Into<Cow<'static, str>>>(url: S) -> Self {
+        LinkAction::Download {
+            url: url.into(),
+            filename: None,
+        }
+    }
+
+    pub fn download_as<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        url: S1,
+        filename: S2,
+    ) -> Self {
+        LinkAction::Download {
+            url: url.into(),
+            filename: Some(filename.into()),
+        }
+    }
+
+    pub fn post<S: Into<Cow<'static, str>>>(url: S) -> Self {
+        LinkAction::Submit {
+            method: SubmitMethod::Post,
+            url: url.into(),
+            csrf_token: None,
+        }
+    }
+
+    pub fn delete<S: Into<Cow<'static, str>>>(url: S) -> Self {
+        LinkAction::Submit {
+            method: SubmitMethod::Delete,
+            url: url.into(),
+            csrf_token: None,
+        }
+    }
+
+    pub fn hx(hx: HxSwap) -> Self {
+        LinkAction::Hx(hx)
+    }
+
+    /// Include an anti-CSRF token when submitting a [`LinkAction::Submit`]
+    /// form. Has no effect on other actions.
+    pub fn with_csrf_token<S: Into<Cow<'static, str>>>(mut self, token: S) -> Self {
+        if let LinkAction::Submit { csrf_token, .. } = &mut self {
+            *csrf_token = Some(token.into());
+        }
+
+        self
     }
 
     /// Contents of the `href` attribute of the link
     ///
-    /// For [`LinkAction::Href`] this is the target URL.
+    /// For [`LinkAction::Href`], [`LinkAction::External`], [`LinkAction::Download`]
+    /// and [`LinkAction::Submit`] this is the target URL.
     ///
-    /// For [`LinkAction::ToggleModal`] this will always be `#`
+    /// For [`LinkAction::ToggleModal`], [`LinkAction::LazyModal`] and
+    /// [`LinkAction::Hx`] this will always be `#`
     pub fn href(&self) -> Cow<'static, str> {
         match self {
-            LinkAction::Href(url) => url.clone(),
-            LinkAction::ToggleModal(_) => "#".into(),
+            LinkAction::Href(url)
+            | LinkAction::External(url)
+            | LinkAction::Download { url, .. }
+            | LinkAction::Submit { url, .. } => url.clone(),
+            LinkAction::ToggleModal(_) | LinkAction::LazyModal(_) | LinkAction::Hx(_) => "#".into(),
         }
     }
 
@@ -44,28 +185,74 @@ impl LinkAction {
     /// [`LinkAction::ToggleModal`] will have extra properties indicating
     /// to [bootstrap](https://getbootstrap.com/docs/4.0/components/modal/)
     /// which modal to toggle.
+    ///
+    /// [`LinkAction::Submit`] links are turned into forms on click by the
+    /// `link-actions.js` script included in every [`Page`](crate::Page).
     pub fn props(&self) -> Cow<'static, str> {
         match self {
             LinkAction::Href(_) => "".into(),
             LinkAction::ToggleModal(modal) => {
                 format!(" data-toggle=\"modal\" data-target=\"#{modal}\"").into()
             }
+            LinkAction::LazyModal(url) => format!(
+                concat!(
+                    r##" data-toggle="modal" data-target="#lazyModal" hx-get="{}""##,
+                    r##" hx-target="#lazyModal .modal-dialog" hx-select=".modal-dialog" hx-swap="outerHTML""##
+                ),
+                escape(url)
+            )
+            .into(),
+            LinkAction::External(_) => r#" target="_blank" rel="noopener""#.into(),
+            LinkAction::Download { filename, .. } => match filename {
+                Some(filename) => format!(" download=\"{}\"", escape(filename)).into(),
+                None => " download".into(),
+            },
+            LinkAction::Submit {
+                method, csrf_token, ..
+            } => match csrf_token
+                .clone()
+                .or_else(|| crate::csrf::CsrfToken::current().map(|token| token.0))
+            {
+                Some(token) => format!(
+                    " data-method=\"{method}\" data-csrf-token=\"{}\"",
+                    escape(&token)
+                )
+                .into(),
+                None => format!(" data-method=\"{method}\"").into(),
+            },
+            LinkAction::Hx(hx) => format!(" {hx}").into(),
+        }
+    }
+
+    /// Markup to append to the label of the link, such as an icon
+    /// indicating that [`LinkAction::External`] links leave the site.
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            LinkAction::External(_) => r#" <i class="fas fa-external-link-alt fa-xs"></i>"#,
+            _ => "",
         }
     }
 }
 
+fn escape(value: &str) -> MarkupDisplay<Html, &str> {
+    MarkupDisplay::new_unsafe(value, Html)
+}
+
 /// A plain link without an icon.
 #[derive(Template)]
 #[template(
     ext = "html",
     source = r#"
 <a class="nav-link" href="{{ action.href() }}" {{ action.props()|safe }}>
-<span>{{ label }}</span></a>
+<span>{{ label }}{{ action.suffix()|safe }}</span></a>
 "#
 )]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlainLink {
     pub label: Cow<'static, str>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub active: bool,
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub action: LinkAction,
 }
 
@@ -94,14 +281,17 @@ impl PlainLink {
     source = r#"
 <a class="nav-link" href="{{ action.href() }}" {{ action.props()|safe }}>
 <i class="fas fa-fw {{ icon }}"></i>
-<span>{{ label }}</span></a>
+<span>{{ label }}{{ action.suffix()|safe }}</span></a>
 "#
 )]
 /// A link with an associated Font-Awesome icon.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IconLink {
     pub label: Cow<'static, str>,
     pub icon: Icon,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub active: bool,
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub action: LinkAction,
 }
 
@@ -176,3 +366,127 @@ impl From<PlainLink> for NavLink {
         NavLink::Plain(value)
     }
 }
+
+#[cfg(feature = "axum")]
+pub use axum_links::method_override;
+
+#[cfg(feature = "axum")]
+mod axum_links {
+    use axum::{extract::Request, http::Method, middleware::Next, response::Response};
+
+    use super::SubmitMethod;
+
+    /// Middleware restoring the method of [`LinkAction::Submit`](super::LinkAction::Submit)
+    /// forms, which browsers can only send as `POST`, from their
+    /// [`SubmitMethod::FIELD`] field.
+    ///
+    /// Requests are routed before any middleware added with
+    /// [`Router::layer`](axum::Router::layer) runs, so the routes must be
+    /// wrapped in an outer router for the `DELETE` method to be matched:
+    ///
+    /// ```no_run
+    /// use axum::{middleware, routing::delete, Router};
+    /// use bootstrap_dashboard::method_override;
+    ///
+    /// let routes = Router::new().route("/users/:id", delete(|| async { "Deleted" }));
+    ///
+    /// let app: Router = Router::new()
+    ///     .fallback_service(routes)
+    ///     .layer(middleware::from_fn(method_override));
+    /// ```
+    pub async fn method_override(request: Request, next: Next) -> Response {
+        if request.method() != Method::POST {
+            return next.run(request).await;
+        }
+
+        let (mut request, method) =
+            match crate::body::form_field(request, SubmitMethod::FIELD).await {
+                Ok(field) => field,
+                Err(response) => return response,
+            };
+
+        if method.is_some_and(|method| method.eq_ignore_ascii_case(SubmitMethod::Delete.as_ref())) {
+            *request.method_mut() = Method::DELETE;
+        }
+
+        next.run(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LinkAction, PlainLink};
+    use crate::htmx::HxSwap;
+
+    #[test]
+    fn link_action_props() {
+        assert_eq!(
+            LinkAction::external("https://example.com").props(),
+            r#" target="_blank" rel="noopener""#
+        );
+        assert_eq!(
+            LinkAction::download_as("/report", "\"report\".csv").props(),
+            r#" download="&quot;report&quot;.csv""#
+        );
+        assert_eq!(
+            LinkAction::delete("/users/1")
+                .with_csrf_token("abc")
+                .props(),
+            r#" data-method="delete" data-csrf-token="abc""#
+        );
+
+        assert_eq!(
+            LinkAction::lazy_modal("/users/1").props(),
+            concat!(
+                r##" data-toggle="modal" data-target="#lazyModal" hx-get="/users/1""##,
+                r##" hx-target="#lazyModal .modal-dialog" hx-select=".modal-dialog" hx-swap="outerHTML""##
+            )
+        );
+
+        let hx = LinkAction::hx(HxSwap::get("/refresh").with_target("#table"));
+        assert_eq!(hx.href(), "#");
+        assert_eq!(hx.props(), r##" hx-get="/refresh" hx-target="#table""##);
+    }
+
+    #[cfg(feature = "axum")]
+    #[tokio::test]
+    async fn override_submitted_method() {
+        use axum::{
+            body::Body,
+            http::{header, Request, StatusCode},
+            middleware,
+            routing::delete,
+            Router,
+        };
+        use tower::ServiceExt;
+
+        let app = Router::new()
+            .fallback_service(Router::new().route("/users/1", delete(|| async { "Deleted" })))
+            .layer(middleware::from_fn(super::method_override));
+
+        let submit = |body: &'static str| {
+            Request::post("/users/1")
+                .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .body(Body::from(body))
+                .unwrap()
+        };
+
+        let response = app
+            .clone()
+            .oneshot(submit("_method=DELETE&csrf_token=abc"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app.oneshot(submit("csrf_token=abc")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[test]
+    fn external_links_are_marked() {
+        let link = PlainLink::new("Docs", LinkAction::external("https://example.com")).to_string();
+
+        assert!(link.contains(r#"href="https://example.com""#));
+        assert!(link.contains("fa-external-link-alt"));
+    }
+}
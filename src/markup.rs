@@ -0,0 +1,88 @@
+use std::{borrow::Cow, fmt::Display};
+
+use askama::{Html, MarkupDisplay};
+
+/// Plain text, HTML-escaped when rendered.
+///
+/// Use this for untrusted content, such as user input, in places which
+/// accept any [`Display`] content and render it verbatim.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Text(pub Cow<'static, str>);
+
+impl Text {
+    pub fn new<S: Into<Cow<'static, str>>>(text: S) -> Self {
+        Text(text.into())
+    }
+}
+
+impl Display for Text {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", MarkupDisplay::new_unsafe(self.0.as_ref(), Html))
+    }
+}
+
+impl<S: Into<Cow<'static, str>>> From<S> for Text {
+    fn from(value: S) -> Self {
+        Text::new(value)
+    }
+}
+
+/// Trusted HTML markup, rendered as-is.
+///
+/// Never construct this from user input, see [`Text`] instead.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Markup(pub Cow<'static, str>);
+
+impl Markup {
+    pub fn new<S: Into<Cow<'static, str>>>(markup: S) -> Self {
+        Markup(markup.into())
+    }
+}
+
+impl Display for Markup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<S: Into<Cow<'static, str>>> From<S> for Markup {
+    fn from(value: S) -> Self {
+        Markup::new(value)
+    }
+}
+
+/// Quoted JSON string literal.
+pub(crate) fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+/// Id derived from the given parts, so the same content always renders the
+/// same markup, across requests as well as htmx re-renders.
+pub(crate) fn content_id<'a, I: IntoIterator<Item = &'a str>>(prefix: &str, parts: I) -> String {
+    // 32-bit FNV-1a, terminating every part so ["ab", "c"] and ["a", "bc"] differ.
+    let mut hash: u32 = 0x811c_9dc5;
+    for part in parts {
+        for byte in part.bytes().chain([0]) {
+            hash ^= u32::from(byte);
+            hash = hash.wrapping_mul(0x0100_0193);
+        }
+    }
+
+    format!("{prefix}-{hash:08x}")
+}
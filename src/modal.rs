@@ -1,19 +1,327 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, fmt::Display};
 
 use askama::Template;
 
+use crate::{
+    card::Card,
+    chart::Chart,
+    form::Form,
+    grid::{Container, Layout, Row},
+    svg::SvgChart,
+    table::{Details, Table},
+    Color, Markup, Text,
+};
+
+/// Bootstrap modal dialog, toggled using [`LinkAction::modal`](crate::LinkAction::modal).
+///
+/// The content can be text, which is escaped, or [`Markup`] and the crate's
+/// components, which are rendered as-is, see [`ModalContent`].
 #[derive(Template)]
 #[template(path = "modal.html")]
-pub struct Modal {
+pub struct Modal<Content: ModalContent = Text> {
     pub id: Cow<'static, str>,
     pub header: Cow<'static, str>,
-    pub content: Cow<'static, str>,
+    pub content: Content,
     pub action: ModalAction,
     pub confirm: Cow<'static, str>,
+    /// Color of the confirm button.
+    pub confirm_color: Color,
+    /// Label of the button dismissing the modal, if any.
+    pub cancel: Option<Cow<'static, str>>,
+    /// Additional buttons shown in the footer, before the confirm button.
+    pub buttons: Vec<ModalButton>,
+    pub size: ModalSize,
+    /// Scroll the body of the modal, rather than the page, when the content is long.
+    pub scrollable: bool,
+    /// Center the modal vertically.
+    pub centered: bool,
+    /// Prevent the modal from closing when clicking outside of it, or pressing escape.
+    pub static_backdrop: bool,
+}
+
+impl<Content: ModalContent> Modal<Content> {
+    pub fn new<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        id: S1,
+        header: S2,
+        content: Content,
+        action: ModalAction,
+    ) -> Self {
+        Modal {
+            id: id.into(),
+            header: header.into(),
+            content,
+            action,
+            confirm: "Confirm".into(),
+            confirm_color: Color::Primary,
+            cancel: Some("Cancel".into()),
+            buttons: Vec::new(),
+            size: ModalSize::Default,
+            scrollable: false,
+            centered: false,
+            static_backdrop: false,
+        }
+    }
+
+    /// Label of the confirmation button.
+    pub fn with_confirm<S: Into<Cow<'static, str>>>(mut self, confirm: S) -> Self {
+        self.confirm = confirm.into();
+        self
+    }
+
+    /// Color of the confirmation button, such as [`Color::Danger`] for
+    /// destructive actions.
+    pub fn with_confirm_color(mut self, color: Color) -> Self {
+        self.confirm_color = color;
+        self
+    }
+
+    /// Label of the cancel button.
+    pub fn with_cancel<S: Into<Cow<'static, str>>>(mut self, cancel: S) -> Self {
+        self.cancel = Some(cancel.into());
+        self
+    }
+
+    pub fn without_cancel(mut self) -> Self {
+        self.cancel = None;
+        self
+    }
+
+    pub fn with_button(mut self, button: ModalButton) -> Self {
+        self.buttons.push(button);
+        self
+    }
+
+    pub fn with_size(mut self, size: ModalSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn scrollable(mut self) -> Self {
+        self.scrollable = true;
+        self
+    }
+
+    pub fn centered(mut self) -> Self {
+        self.centered = true;
+        self
+    }
+
+    pub fn with_static_backdrop(mut self) -> Self {
+        self.static_backdrop = true;
+        self
+    }
+
+    pub fn replace_content<NewContent: ModalContent>(
+        self,
+        content: NewContent,
+    ) -> Modal<NewContent> {
+        Modal {
+            id: self.id,
+            header: self.header,
+            content,
+            action: self.action,
+            confirm: self.confirm,
+            confirm_color: self.confirm_color,
+            cancel: self.cancel,
+            buttons: self.buttons,
+            size: self.size,
+            scrollable: self.scrollable,
+            centered: self.centered,
+            static_backdrop: self.static_backdrop,
+        }
+    }
+
+    fn escaped(&self) -> bool {
+        Content::TEXT
+    }
+
+    fn confirm_button(&self) -> ModalButton {
+        ModalButton {
+            label: self.confirm.clone(),
+            color: self.confirm_color,
+            action: self.action.clone(),
+        }
+    }
+}
+
+impl Modal<Form> {
+    /// Modal containing a [`Form`], submitted by the modal's confirm button.
+    ///
+    /// When submitted via htmx (see [`Form::hx_post`]), validation feedback
+    /// is shown in place, and the modal closes itself once the server responds
+    /// with [`Submitted`](crate::form::Submitted).
+    ///
+    /// The form's own submit button is removed, so make sure to also call
+    /// [`Form::without_submit`] when re-rendering it with validation feedback.
+    pub fn form<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        id: S1,
+        header: S2,
+        form: Form,
+    ) -> Self {
+        let action = ModalAction::Submit(form.id.clone());
+        Modal::new(id, header, form.without_submit(), action)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum ModalAction {
     Post(Cow<'static, str>),
     Get(Cow<'static, str>),
+    /// Submit the form with the given id contained within the modal.
+    Submit(Cow<'static, str>),
+    /// Close the modal.
+    Dismiss,
+}
+
+/// Button in the footer of a [`Modal`].
+#[derive(Debug, Clone, Template)]
+#[template(
+    ext = "html",
+    source = r#"
+{% match action %}
+{% when ModalAction::Post with (href) %}
+<form method="POST" action="{{ href }}">
+    {{ crate::csrf::CsrfInput|safe }}
+    <button type="submit" class="btn btn-{{ color }}">{{ label }}</button>
+</form>
+{% when ModalAction::Get with (href) %}
+<a class="btn btn-{{ color }}" href="{{ href }}">{{ label }}</a>
+{% when ModalAction::Submit with (form) %}
+<button type="submit" form="{{ form }}" class="btn btn-{{ color }}">{{ label }}</button>
+{% when ModalAction::Dismiss %}
+<button type="button" class="btn btn-{{ color }}" data-dismiss="modal">{{ label }}</button>
+{% endmatch %}
+"#
+)]
+pub struct ModalButton {
+    pub label: Cow<'static, str>,
+    pub color: Color,
+    pub action: ModalAction,
+}
+
+impl ModalButton {
+    pub fn new<S: Into<Cow<'static, str>>>(label: S, action: ModalAction) -> Self {
+        ModalButton {
+            label: label.into(),
+            color: Color::Secondary,
+            action,
+        }
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+/// Content of a [`Modal`].
+///
+/// Strings are plain text, and escaped like [`Text`]. [`Markup`] and the
+/// crate's components are rendered as-is. Implement it for your own
+/// templates, which are responsible for escaping their fields.
+pub trait ModalContent: Display {
+    /// Whether the content is plain text, which must be escaped.
+    const TEXT: bool = false;
+}
+
+impl ModalContent for &str {
+    const TEXT: bool = true;
+}
+
+impl ModalContent for String {
+    const TEXT: bool = true;
+}
+
+impl ModalContent for Cow<'_, str> {
+    const TEXT: bool = true;
+}
+
+impl ModalContent for Text {}
+impl ModalContent for Markup {}
+impl ModalContent for Form {}
+impl<Content: Display> ModalContent for Card<Content> {}
+impl<TableRow> ModalContent for Table<TableRow> {}
+impl ModalContent for Details {}
+impl ModalContent for Row {}
+impl ModalContent for Container {}
+impl ModalContent for Layout {}
+impl ModalContent for Chart {}
+impl ModalContent for SvgChart {}
+
+/// Width of a [`Modal`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ModalSize {
+    Small,
+    #[default]
+    Default,
+    Large,
+    ExtraLarge,
+    /// Covers the entire viewport.
+    Fullscreen,
+}
+
+impl ModalSize {
+    /// Class added to the `modal-dialog`, if any.
+    pub fn class(&self) -> &'static str {
+        match self {
+            ModalSize::Small => " modal-sm",
+            ModalSize::Default => "",
+            ModalSize::Large => " modal-lg",
+            ModalSize::ExtraLarge => " modal-xl",
+            ModalSize::Fullscreen => " modal-fullscreen",
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn modal_content_escaping() {
+    let text = Modal::new(
+        "m",
+        "Header",
+        Text::new("<b>bold</b>"),
+        ModalAction::Get("/".into()),
+    );
+    assert!(text.to_string().contains("&lt;b&gt;bold&lt;/b&gt;"));
+
+    let markup = text.replace_content(Markup::new("<b>bold</b>"));
+    assert!(markup.to_string().contains("<b>bold</b>"));
+
+    let card = markup.replace_content(Card::new("Card body").with_header("Card"));
+    assert!(card.to_string().contains(r#"<div class="card shadow">"#));
+
+    let text = card.replace_content("<script>alert(1)</script>");
+    assert!(text
+        .to_string()
+        .contains(r#"<div class="modal-body">&lt;script&gt;alert(1)&lt;/script&gt;</div>"#));
+    let text = text.replace_content(String::from("<script>"));
+    assert!(text.to_string().contains("&lt;script&gt;"));
+    assert!(!text.to_string().contains("<script>"));
+}
+
+#[cfg(test)]
+#[test]
+fn modal_options() {
+    let rendered = Modal::new(
+        "deleteModal",
+        "Delete?",
+        Text::new("This cannot be undone."),
+        ModalAction::Post("/delete".into()),
+    )
+    .with_size(ModalSize::Large)
+    .centered()
+    .with_static_backdrop()
+    .with_confirm_color(Color::Danger)
+    .without_cancel()
+    .with_button(ModalButton::new(
+        "Archive instead",
+        ModalAction::Post("/archive".into()),
+    ))
+    .to_string();
+
+    assert!(rendered.contains(r#"class="modal-dialog modal-lg modal-dialog-centered""#));
+    assert!(rendered.contains(r#"data-backdrop="static""#));
+    assert!(rendered.contains(r#"class="btn btn-danger""#));
+    assert!(rendered.contains("Archive instead"));
+    assert!(!rendered.contains("Cancel"));
 }
@@ -2,18 +2,65 @@ use std::borrow::Cow;
 
 use askama::Template;
 
+use crate::{card::CardButton, Breadcrumbs, Icon};
+
+/// Page title, shown in the topbar of the [`Dashboard`](crate::Dashboard)
+/// by default, or above the content using [`PageHeader::in_content`].
 #[derive(Template)]
 #[template(path = "page_header.html")]
 pub struct PageHeader {
     label: Cow<'static, str>,
+    subtitle: Option<Cow<'static, str>>,
+    icon: Option<Icon>,
+    breadcrumbs: Option<Breadcrumbs>,
+    actions: Vec<CardButton>,
+    in_content: bool,
 }
 
 impl PageHeader {
     pub fn new<S: Into<Cow<'static, str>>>(label: S) -> Self {
         PageHeader {
             label: label.into(),
+            subtitle: None,
+            icon: None,
+            breadcrumbs: None,
+            actions: Vec::new(),
+            in_content: false,
         }
     }
+
+    pub fn with_subtitle<S: Into<Cow<'static, str>>>(mut self, subtitle: S) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    pub fn with_icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Show a [`Breadcrumbs`] trail above the label.
+    pub fn with_breadcrumbs(mut self, breadcrumbs: Breadcrumbs) -> Self {
+        self.breadcrumbs = Some(breadcrumbs);
+        self
+    }
+
+    /// Add a right-aligned action button, such as "Generate Report".
+    pub fn with_action(mut self, action: CardButton) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Render the header at the top of the page content, rather than in the topbar.
+    pub fn in_content(mut self) -> Self {
+        self.in_content = true;
+        self
+    }
+
+    /// Whether the header is rendered in the topbar.
+    pub fn is_in_topbar(&self) -> bool {
+        !self.in_content
+    }
 }
 
 impl<T: Into<Cow<'static, str>>> From<T> for PageHeader {
@@ -21,3 +68,15 @@ impl<T: Into<Cow<'static, str>>> From<T> for PageHeader {
         PageHeader::new(value)
     }
 }
+
+/// Uses the label of the last breadcrumb as the page header label.
+impl From<Breadcrumbs> for PageHeader {
+    fn from(value: Breadcrumbs) -> Self {
+        let label = value
+            .last()
+            .map(|crumb| crumb.label.clone())
+            .unwrap_or_default();
+
+        PageHeader::new(label).with_breadcrumbs(value)
+    }
+}
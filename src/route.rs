@@ -0,0 +1,167 @@
+//! Type-safe references to the routes of your application.
+//!
+//! Implement [`Route`] for your own route enums or structs, and use them
+//! with [`LinkAction::route`](crate::LinkAction::route), or anywhere else a
+//! URL is accepted via [`Route::url`], instead of hand-written strings.
+//! [`Sidebar::with_active_route`](crate::Sidebar::with_active_route) uses
+//! the same definition to highlight the current page.
+//!
+//! # Example
+//! ```rust
+//! use std::borrow::Cow;
+//! use bootstrap_dashboard::{route::Route, LinkAction};
+//!
+//! enum AppRoute {
+//!     Users,
+//!     User { id: u32 },
+//!     Search { query: String, page: u32 },
+//! }
+//!
+//! impl Route for AppRoute {
+//!     fn path(&self) -> Cow<'static, str> {
+//!         match self {
+//!             AppRoute::Users => "/users".into(),
+//!             AppRoute::User { id } => format!("/users/{id}").into(),
+//!             AppRoute::Search { .. } => "/search".into(),
+//!         }
+//!     }
+//!
+//!     fn query(&self) -> Vec<(&'static str, String)> {
+//!         match self {
+//!             AppRoute::Search { query, page } => {
+//!                 vec![("q", query.clone()), ("page", page.to_string())]
+//!             }
+//!             _ => Vec::new(),
+//!         }
+//!     }
+//! }
+//!
+//! let search = AppRoute::Search { query: "rust & askama".into(), page: 2 };
+//! assert_eq!(search.url(), "/search?q=rust%20%26%20askama&page=2");
+//!
+//! let link = LinkAction::route(&AppRoute::User { id: 42 });
+//! assert_eq!(link.href(), "/users/42");
+//! ```
+use std::{borrow::Cow, fmt::Write};
+
+/// A typed reference to a page or endpoint of your application.
+pub trait Route {
+    /// Path of the route, with any path parameters filled in.
+    ///
+    /// Parameters which may contain reserved characters should be
+    /// escaped using [`encode_component`].
+    fn path(&self) -> Cow<'static, str>;
+
+    /// Query parameters of the route, encoded by [`Route::url`].
+    fn query(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    /// Full URL of the route, including the query string, if any.
+    fn url(&self) -> String {
+        let mut url = self.path().into_owned();
+
+        for (i, (key, value)) in self.query().iter().enumerate() {
+            url.push(if i == 0 { '?' } else { '&' });
+            url.push_str(&encode_component(key));
+            url.push('=');
+            url.push_str(&encode_component(value));
+        }
+
+        url
+    }
+
+    /// Whether the given path refers to this route, ignoring any query string.
+    fn matches(&self, path: &str) -> bool {
+        let path = path.split_once('?').map_or(path, |(path, _)| path);
+        path == self.path()
+    }
+}
+
+/// Percent-encode everything but the unreserved characters of
+/// [RFC 3986](https://datatracker.ietf.org/doc/html/rfc3986#section-2.3),
+/// making the value safe for use as a path segment or query parameter.
+pub fn encode_component(value: &str) -> Cow<'_, str> {
+    let is_unreserved = |b: u8| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~');
+
+    if value.bytes().all(is_unreserved) {
+        return Cow::Borrowed(value);
+    }
+
+    let mut encoded = String::with_capacity(value.len() * 3);
+    for byte in value.bytes() {
+        if is_unreserved(byte) {
+            encoded.push(byte as char);
+        } else {
+            let _ = write!(encoded, "%{byte:02X}");
+        }
+    }
+
+    Cow::Owned(encoded)
+}
+
+/// Decode a percent-encoded path segment or query parameter, also
+/// treating `+` as a space as submitted by HTML forms.
+///
+/// Invalid escapes are kept as-is, and invalid UTF-8 is replaced.
+pub fn decode_component(value: &str) -> Cow<'_, str> {
+    if !value.bytes().any(|b| b == b'%' || b == b'+') {
+        return Cow::Borrowed(value);
+    }
+
+    let hex = |b: u8| (b as char).to_digit(16).map(|d| d as u8);
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                match (
+                    bytes.get(i + 1).and_then(|b| hex(*b)),
+                    bytes.get(i + 2).and_then(|b| hex(*b)),
+                ) {
+                    (Some(high), Some(low)) => {
+                        decoded.push(high << 4 | low);
+                        i += 2;
+                    }
+                    _ => decoded.push(b'%'),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+
+    Cow::Owned(String::from_utf8_lossy(&decoded).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_component, encode_component, Route};
+    use std::borrow::Cow;
+
+    struct Profile<'a>(&'a str);
+
+    impl Route for Profile<'_> {
+        fn path(&self) -> Cow<'static, str> {
+            format!("/profiles/{}", encode_component(self.0)).into()
+        }
+
+        fn query(&self) -> Vec<(&'static str, String)> {
+            vec![("tab", "activity log".to_string())]
+        }
+    }
+
+    #[test]
+    fn route_urls_are_encoded() {
+        let route = Profile("jane/doe");
+
+        assert_eq!(route.url(), "/profiles/jane%2Fdoe?tab=activity%20log");
+        assert!(route.matches("/profiles/jane%2Fdoe?tab=settings"));
+        assert!(!route.matches("/profiles/jane"));
+        assert_eq!(encode_component("æ"), "%C3%A6");
+        assert_eq!(decode_component("%C3%A6+and%20more%2"), "æ and more%2");
+    }
+}
@@ -1,10 +1,13 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, fmt::Display};
 
 use askama::Template;
 
 use crate::{
+    card::CardButton,
+    cookies::{self, CookieState},
     links::{IconLink, LinkAction, NavLink, PlainLink},
-    Icon,
+    markup::content_id,
+    Breadcrumbs, Icon, Route,
 };
 
 #[derive(Template)]
@@ -21,6 +24,8 @@ use crate::{
 </div>
 "#
 )]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum BrandIcon {
     Icon(Icon),
     Image(Cow<'static, str>),
@@ -34,10 +39,16 @@ impl From<Icon> for BrandIcon {
 
 /// Sidebar menu [`Group`]s have optional labels, and are always
 /// separated by a divider.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Group {
     /// Optional Group label.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub label: Option<Cow<'static, str>>,
     /// Group's navigation items.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub items: Vec<NavItem>,
 }
 
@@ -98,7 +109,8 @@ pub enum NavItem {
         /// Whether the group should be collapsed or not.
         ///
         /// Collapsed by default, expanded when a child link is active
-        /// or clicked by the user.
+        /// or clicked by the user. See [`SidebarState`] for remembering
+        /// the latter across page loads.
         collapsed: bool,
     },
 }
@@ -138,12 +150,28 @@ impl NavItem {
             NavItem::Collapsible { label, .. } => label,
         }
     }
+
+    /// Element id of the collapsible menu, derived from a hash of its label,
+    /// so menus with similar labels such as `Users & Roles` and
+    /// `Users / Roles` get distinct ids.
+    ///
+    /// This is the id stored in the [`SidebarState`] cookie when the menu
+    /// is expanded by the user.
+    pub fn collapse_id(&self) -> String {
+        content_id("collapse", [self.label().as_ref()])
+    }
 }
 
 /// [`SubGroup`]s are an element of [`NavItem::Collapsible`] sidebar objects,
 /// which allow you to group links using a de-emphasized label.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubGroup {
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub label: Option<Cow<'static, str>>,
+    #[cfg_attr(feature = "serde", serde(default))]
     pub links: Vec<PlainLink>,
 }
 
@@ -195,12 +223,54 @@ impl SubGroup {
 /// Each [`SubGroup`]:
 /// * Optionally contains a header label.
 /// * Contains many [`PlainLink`]s.
+///
+/// ## Configuration
+///
+/// With the `serde` feature enabled, the whole structure can be loaded from
+/// any serde-supported format. Items with `subgroups` become
+/// [`NavItem::Collapsible`], all others become links:
+///
+/// ```toml
+/// name = "Dashboard"
+/// brand_icon = { icon = "laugh-wink" }
+///
+/// [[groups]]
+/// items = [{ label = "Dashboard", icon = "tachometer-alt", href = "/" }]
+///
+/// [[groups]]
+/// label = "Settings"
+///
+/// [[groups.items]]
+/// label = "Users"
+/// icon = "users"
+/// subgroups = [{ links = [{ label = "List", href = "/users" }] }]
+/// ```
 #[derive(Template)]
 #[template(path = "sidebar.html")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Sidebar {
     pub name: Cow<'static, str>,
     pub brand_icon: BrandIcon,
+    #[cfg_attr(feature = "serde", serde(default))]
     pub groups: Vec<Group>,
+    /// Render the sidebar in its narrow, toggled state.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub toggled: bool,
+    /// Call-to-action card shown below the sidebar toggler.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub card: Option<SidebarCard>,
+    /// Arbitrary content rendered at the bottom of the sidebar.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub bottom: Option<Box<dyn Display + Send + Sync>>,
+    /// Small print at the very bottom of the sidebar, such as a version number.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub footer: Option<Cow<'static, str>>,
 }
 
 impl Sidebar {
@@ -209,6 +279,10 @@ impl Sidebar {
             name: name.into(),
             brand_icon: brand_icon.into(),
             groups: Vec::new(),
+            toggled: false,
+            card: None,
+            bottom: None,
+            footer: None,
         }
     }
 
@@ -217,40 +291,105 @@ impl Sidebar {
         self
     }
 
+    pub fn with_card(mut self, card: SidebarCard) -> Self {
+        self.card = Some(card);
+        self
+    }
+
+    pub fn with_bottom<D: Display + Send + Sync + 'static>(mut self, content: D) -> Self {
+        self.bottom = Some(Box::new(content));
+        self
+    }
+
+    pub fn with_footer<S: Into<Cow<'static, str>>>(mut self, footer: S) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
+    /// Derive the [`Breadcrumbs`] trail leading to the active link, made up
+    /// of the labels of the [`Group`], the [`NavItem::Collapsible`] and
+    /// finally the active link itself.
+    ///
+    /// Returns `None` if no link is active, see [`Sidebar::with_active_label`]
+    /// and [`Sidebar::with_active_from_path`].
+    pub fn breadcrumbs(&self) -> Option<Breadcrumbs> {
+        for group in &self.groups {
+            let mut breadcrumbs = Breadcrumbs::new();
+            if let Some(label) = &group.label {
+                breadcrumbs = breadcrumbs.with_label(label.clone());
+            }
+
+            for item in &group.items {
+                match item {
+                    NavItem::Link(link) if link.active() => {
+                        return Some(
+                            breadcrumbs.with_link(link.label().clone(), link.action().clone()),
+                        );
+                    }
+                    NavItem::Link(_) => {}
+                    NavItem::Collapsible {
+                        label, subgroups, ..
+                    } => {
+                        if let Some(link) = subgroups
+                            .iter()
+                            .flat_map(|subgroup| &subgroup.links)
+                            .find(|link| link.active)
+                        {
+                            return Some(
+                                breadcrumbs
+                                    .with_label(label.clone())
+                                    .with_link(link.label.clone(), link.action.clone()),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Restore the toggled state of the sidebar, and expand the collapsible
+    /// menus which the user left open.
+    pub fn with_state(mut self, state: &SidebarState) -> Self {
+        self.toggled = state.toggled;
+
+        for group in &mut self.groups {
+            for item in &mut group.items {
+                let id = item.collapse_id();
+                if let NavItem::Collapsible { collapsed, .. } = item {
+                    if state.expanded.contains(&id) {
+                        *collapsed = false;
+                    }
+                }
+            }
+        }
+
+        self
+    }
+
     /// Sets the `active` field of the first [`IconLink`] or [`PlainLink`] whose
     /// label matches the provided `active_label`.
     ///
     /// # Example
     /// ```rust
-    /// # use bootstrap_dashboard::{Dashboard, SubGroup, Group, IconLink, icons, LinkAction, Sidebar};
-    /// let dashboard = Dashboard {
-    ///     sidebar: Sidebar {
-    ///         groups: vec![
-    ///             Group::unlabeled()
-    ///                 .with_item(IconLink::new(
-    ///                     "Dashboard",
-    ///                     icons::fa::TACHOMETER_ALT,
-    ///                     LinkAction::to("/"),
-    ///                 ))
-    ///                 // This is the item which wil be marked "active".
-    ///                 .with_item(IconLink::new(
-    ///                     "Configuration",
-    ///                     icons::fa::COGS,
-    ///                     LinkAction::to("/"),
-    ///                 )),
-    ///         ],
-    ///         // ...
-    /// #       name: "".into(),
-    /// #       logo: icons::fa::LAUGH_WINK
-    ///     },
-    ///     // ...
-    /// #   copyright: None,
-    /// #   page_header: None,
-    /// #   alerts: None,
-    /// #   userinfo: None,
-    /// #   content: "",
-    /// }.with_active_label("Configuration");
-    ///
+    /// # use bootstrap_dashboard::{Group, IconLink, icons, LinkAction, Sidebar};
+    /// let sidebar = Sidebar::new("Dashboard", icons::fa::LAUGH_WINK)
+    ///     .with_group(
+    ///         Group::unlabeled()
+    ///             .with_item(IconLink::new(
+    ///                 "Dashboard",
+    ///                 icons::fa::TACHOMETER_ALT,
+    ///                 LinkAction::to("/"),
+    ///             ))
+    ///             // This is the item which wil be marked "active".
+    ///             .with_item(IconLink::new(
+    ///                 "Configuration",
+    ///                 icons::fa::COGS,
+    ///                 LinkAction::to("/configuration"),
+    ///             )),
+    ///     )
+    ///     .with_active_label("Configuration");
     /// ```
     pub fn with_active_label(self, active_label: &str) -> Self {
         self.with_active(|_, label| label == active_label)
@@ -270,12 +409,23 @@ impl Sidebar {
         })
     }
 
+    /// Mark the link pointing to the given [`Route`] as active.
+    ///
+    /// Only the path of the link is compared, so links carrying a query
+    /// string still match.
+    pub fn with_active_route<R: Route>(self, route: &R) -> Self {
+        self.with_active(|action, _| match action {
+            LinkAction::Href(url) => route.matches(url),
+            _ => false,
+        })
+    }
+
     fn with_active(mut self, selector: impl for<'r> Fn(&'r LinkAction, &'r str) -> bool) -> Self {
         'outer: for group in &mut self.groups {
             for item in &mut group.items {
                 match item {
                     NavItem::Link(link) => {
-                        if selector(&link.action(), &link.label()) {
+                        if selector(link.action(), link.label()) {
                             match link {
                                 NavLink::Plain(plain) => plain.active = true,
                                 NavLink::Icon(icon) => icon.active = true,
@@ -305,3 +455,379 @@ impl Sidebar {
         self
     }
 }
+
+/// Promotional or informational card shown at the bottom of the [`Sidebar`].
+///
+/// Hidden on small screens, as well as when the sidebar is toggled.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SidebarCard {
+    /// Optional illustration shown above the text.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub image: Option<Cow<'static, str>>,
+    pub text: Cow<'static, str>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub button: Option<CardButton>,
+}
+
+impl SidebarCard {
+    pub fn new<S: Into<Cow<'static, str>>>(text: S) -> Self {
+        SidebarCard {
+            image: None,
+            text: text.into(),
+            button: None,
+        }
+    }
+
+    pub fn with_image<S: Into<Cow<'static, str>>>(mut self, image: S) -> Self {
+        self.image = Some(image.into());
+        self
+    }
+
+    pub fn with_button(mut self, button: CardButton) -> Self {
+        self.button = Some(button);
+        self
+    }
+}
+
+/// Sidebar state persisted by the browser across page loads.
+///
+/// The `sidebar-state.js` script included in every [`Page`](crate::Page)
+/// stores whether the sidebar is toggled and which collapsible menus are
+/// expanded in the [`SidebarState::TOGGLED_COOKIE`] and
+/// [`SidebarState::EXPANDED_COOKIE`] cookies respectively. Read them back
+/// using [`SidebarState::from_cookie_header`], or the axum extractor when the
+/// `axum` feature is enabled, and apply them with [`Sidebar::with_state`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SidebarState {
+    /// Whether the sidebar was toggled into its narrow form.
+    pub toggled: bool,
+    /// [`NavItem::collapse_id`]s of the expanded collapsible menus.
+    pub expanded: Vec<String>,
+}
+
+impl SidebarState {
+    pub const TOGGLED_COOKIE: &'static str = "sidebarToggled";
+    pub const EXPANDED_COOKIE: &'static str = "sidebarExpanded";
+
+    /// Parse the state from the contents of a `Cookie` header.
+    ///
+    /// Unrelated cookies are ignored, and missing cookies leave the
+    /// corresponding state at its default.
+    pub fn from_cookie_header(header: &str) -> Self {
+        cookies::from_header(header)
+    }
+}
+
+impl CookieState for SidebarState {
+    fn apply_cookie(&mut self, name: &str, value: &str) {
+        match name {
+            Self::TOGGLED_COOKIE => self.toggled = value == "true",
+            Self::EXPANDED_COOKIE => self.expanded.extend(cookies::ids(value)),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(feature = "axum")]
+mod axum_state {
+    use std::convert::Infallible;
+
+    use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+
+    use super::SidebarState;
+
+    /// Extracts the [`SidebarState`] from the request cookies.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use axum::response::{Html, IntoResponse};
+    /// # use bootstrap_dashboard::{icons, Dashboard, Page, Sidebar, SidebarState};
+    /// async fn index(state: SidebarState) -> impl IntoResponse {
+    ///     let sidebar = Sidebar::new("Dashboard", icons::fa::LAUGH_WINK).with_state(&state);
+    ///
+    ///     Html(
+    ///         Page::new("Dashboard", "/static")
+    ///             .with_content(Dashboard::default().with_sidebar(sidebar))
+    ///             .to_string(),
+    ///     )
+    /// }
+    /// ```
+    #[async_trait]
+    impl<S: Send + Sync> FromRequestParts<S> for SidebarState {
+        type Rejection = Infallible;
+
+        async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+            Ok(crate::cookies::from_headers(&parts.headers))
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_nav_item {
+    use std::borrow::Cow;
+
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{NavItem, SubGroup};
+    use crate::{
+        links::{IconLink, LinkAction, PlainLink},
+        Icon,
+    };
+
+    /// Flattened representation of a [`NavItem`]. Whether the item is a link
+    /// or a collapsible menu is decided by which fields are present.
+    #[derive(Deserialize)]
+    struct RawNavItem {
+        label: Cow<'static, str>,
+        #[serde(default)]
+        icon: Option<Icon>,
+        #[serde(default)]
+        subgroups: Option<Vec<SubGroup>>,
+        #[serde(flatten)]
+        action: Option<LinkAction>,
+    }
+
+    #[derive(Serialize)]
+    struct NavItemRef<'a> {
+        label: &'a str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        icon: Option<&'a Icon>,
+        #[serde(flatten)]
+        action: Option<&'a LinkAction>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        subgroups: Option<&'a [SubGroup]>,
+    }
+
+    impl TryFrom<RawNavItem> for NavItem {
+        type Error = String;
+
+        fn try_from(raw: RawNavItem) -> Result<Self, Self::Error> {
+            let label = raw.label;
+
+            match (raw.subgroups, raw.action, raw.icon) {
+                (Some(_), Some(_), _) => Err(format!(
+                    "menu item \"{label}\" cannot have both a link action and subgroups"
+                )),
+                (Some(_), None, None) => Err(format!(
+                    "collapsible menu item \"{label}\" is missing an icon"
+                )),
+                (Some(subgroups), None, Some(_)) if subgroups.is_empty() => Err(format!(
+                    "collapsible menu item \"{label}\" has no subgroups"
+                )),
+                (Some(subgroups), None, Some(icon)) => {
+                    Ok(NavItem::collapsible(label, icon, subgroups))
+                }
+                (None, None, _) => Err(format!(
+                    "menu item \"{label}\" needs either a link action (such as `href`) or `subgroups`"
+                )),
+                (None, Some(action), None) => Ok(PlainLink::new(label, action).into()),
+                (None, Some(action), Some(icon)) => Ok(IconLink::new(label, icon, action).into()),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for NavItem {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            NavItem::try_from(RawNavItem::deserialize(deserializer)?).map_err(D::Error::custom)
+        }
+    }
+
+    impl Serialize for NavItem {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self {
+                NavItem::Link(link) => NavItemRef {
+                    label: link.label(),
+                    icon: link.icon(),
+                    action: Some(link.action()),
+                    subgroups: None,
+                },
+                NavItem::Collapsible {
+                    label,
+                    icon,
+                    subgroups,
+                    ..
+                } => NavItemRef {
+                    label,
+                    icon: Some(icon),
+                    action: None,
+                    subgroups: Some(subgroups),
+                },
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NavItem, Sidebar, SidebarCard, SidebarState};
+    use crate::{card::CardButton, icons, Group, LinkAction, Markup, PlainLink, SubGroup};
+
+    fn menu(label: &'static str) -> NavItem {
+        NavItem::collapsible(
+            label,
+            icons::fa::LIST,
+            vec![SubGroup::unlabeled().with_link(PlainLink::new("All", LinkAction::to("/")))],
+        )
+    }
+
+    #[test]
+    fn restore_sidebar_state() {
+        let reports = menu("Reports").collapse_id();
+        let state = SidebarState::from_cookie_header(&format!(
+            "session=abc; sidebarToggled=true; sidebarExpanded={reports}.collapse-unknown"
+        ));
+        assert_eq!(
+            state,
+            SidebarState {
+                toggled: true,
+                expanded: vec![reports, "collapse-unknown".into()],
+            }
+        );
+
+        let sidebar = Sidebar::new("Dashboard", icons::fa::LAUGH_WINK)
+            .with_group(Group::unlabeled().with_item(menu("Reports")))
+            .with_state(&state);
+
+        assert!(sidebar.toggled);
+        assert!(matches!(
+            sidebar.groups[0].items[0],
+            NavItem::Collapsible {
+                collapsed: false,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn distinct_collapse_ids() {
+        let ids: Vec<_> = ["Users & Roles", "Users / Roles", "Zählen", "Größen"]
+            .into_iter()
+            .map(|label| menu(label).collapse_id())
+            .collect();
+
+        assert_ne!(ids[0], ids[1]);
+        assert_ne!(ids[2], ids[3]);
+        assert_eq!(ids[0], menu("Users & Roles").collapse_id());
+        assert!(ids
+            .iter()
+            .all(|id| id.starts_with("collapse-") && !id.contains('.')));
+    }
+
+    #[test]
+    fn render_card_bottom_and_footer() {
+        let sidebar = Sidebar::new("Dashboard", icons::fa::LAUGH_WINK)
+            .with_card(
+                SidebarCard::new("Upgrade to <Pro>")
+                    .with_image("/img/undraw_rocket.svg")
+                    .with_button(
+                        CardButton::new("Upgrade").with_action(LinkAction::to("/upgrade")),
+                    ),
+            )
+            .with_bottom(Markup::new(r#"<div id="sidebarExtra"></div>"#))
+            .with_footer("v1.2.3");
+
+        let html = sidebar.to_string();
+        let card = html
+            .find(r#"<div class="sidebar-card d-none d-lg-flex">"#)
+            .unwrap();
+        let bottom = html.find(r#"<div id="sidebarExtra"></div>"#).unwrap();
+        let footer = html.find(">v1.2.3</div>").unwrap();
+
+        assert!(card < bottom && bottom < footer);
+        assert!(html.contains(r#"src="/img/undraw_rocket.svg""#));
+        assert!(html.contains("Upgrade to &lt;Pro&gt;"));
+        assert!(html.contains(r#"href="/upgrade""#));
+        assert!(!Sidebar::new("Dashboard", icons::fa::LAUGH_WINK)
+            .to_string()
+            .contains("sidebar-card"));
+    }
+
+    #[test]
+    fn breadcrumbs_from_active_link() {
+        let sidebar = Sidebar::new("Dashboard", icons::fa::LAUGH_WINK)
+            .with_group(Group::new("Settings").with_item(NavItem::collapsible(
+                "Users",
+                icons::fa::USERS,
+                vec![
+                        SubGroup::new("Manage")
+                            .with_link(PlainLink::new("List", LinkAction::to("/users")))
+                            .with_link(PlainLink::new("Invite", LinkAction::to("/users/invite"))),
+                    ],
+            )))
+            .with_active_from_path("/users/invite");
+
+        let labels: Vec<_> = sidebar
+            .breadcrumbs()
+            .unwrap()
+            .0
+            .into_iter()
+            .map(|crumb| crumb.label)
+            .collect();
+
+        assert_eq!(labels, ["Settings", "Users", "Invite"]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_sidebar_from_toml() {
+        use crate::links::NavLink;
+
+        let sidebar: Sidebar = toml::from_str(include_str!("../examples/sidebar.toml")).unwrap();
+
+        assert_eq!(sidebar.groups.len(), 2);
+        assert!(matches!(
+            &sidebar.groups[0].items[1],
+            NavItem::Link(NavLink::Icon(link)) if link.icon.to_string() == "fa-cogs"
+        ));
+        assert!(matches!(
+            &sidebar.groups[1].items[0],
+            NavItem::Collapsible { subgroups, collapsed: true, .. } if subgroups.len() == 2
+        ));
+        assert!(matches!(
+            &sidebar.groups[1].items[1],
+            NavItem::Link(NavLink::Plain(link))
+                if matches!(&link.action, LinkAction::ToggleModal(modal) if modal == "logoutModal")
+        ));
+
+        let roundtrip: Sidebar = toml::from_str(&toml::to_string(&sidebar).unwrap()).unwrap();
+        assert_eq!(roundtrip.to_string(), sidebar.to_string());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn invalid_entries_are_reported() {
+        let Err(err) = toml::from_str::<Sidebar>(
+            r#"
+            name = "Dashboard"
+            brand_icon = { icon = "laugh-wink" }
+
+            [[groups]]
+            items = [
+                { label = "Fine", href = "/" },
+                { label = "Broken", icon = "cogs" },
+            ]
+            "#,
+        ) else {
+            panic!("missing link action was accepted");
+        };
+        assert!(err.message().contains("\"Broken\""), "{err}");
+        assert!(err.span().is_some());
+
+        let Err(err) = toml::from_str::<Sidebar>(
+            r#"
+            name = "Dashboard"
+            brand_icon = { icon = "Laugh Wink" }
+            "#,
+        ) else {
+            panic!("invalid icon name was accepted");
+        };
+        assert!(err.message().contains("\"Laugh Wink\""), "{err}");
+    }
+}
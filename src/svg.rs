@@ -0,0 +1,818 @@
+//! Charts rendered to inline SVG on the server, without any JavaScript.
+//!
+//! Unlike [`Chart`](crate::chart::Chart)s, these work in printed reports and
+//! under a strict Content Security Policy. Values are shown in tooltips
+//! using SVG `<title>` elements, and the same chart always renders the same
+//! markup, so it can be compared in snapshot tests.
+//!
+//! ```
+//! use bootstrap_dashboard::{
+//!     card::{Card, StatCard},
+//!     chart::{Dataset, ValueFormat},
+//!     icons,
+//!     svg::{Sparkline, SvgChart},
+//!     Color,
+//! };
+//!
+//! let chart = SvgChart::bar()
+//!     .with_labels(["Q1", "Q2", "Q3", "Q4"])
+//!     .with_dataset(Dataset::new("Revenue", [120.0, 180.5, 95.0, 210.0]))
+//!     .with_format(ValueFormat::new().with_prefix("$"))
+//!     .with_legend();
+//!
+//! let card = Card::new(chart).with_header("Revenue");
+//! assert!(card.to_string().contains("<title>Q2\nRevenue: $180.5</title>"));
+//!
+//! let stat = StatCard::new("Visitors", "1,024", Color::Success, icons::fa::USERS)
+//!     .with_sparkline(Sparkline::new([3.0, 5.0, 4.0, 8.0]).with_color(Color::Success));
+//! assert!(stat.to_string().contains(r#"class="sparkline""#));
+//! ```
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+};
+
+use askama::{Html, MarkupDisplay};
+
+use crate::{
+    chart::{rgba, Chart, ChartKind, Dataset, ValueFormat},
+    Color,
+};
+
+const TEXT_COLOR: &str = "#858796";
+const GRID_COLOR: &str = "#eaecf4";
+const FONT_SIZE: f64 = 11.0;
+/// Approximate width of a character at [`FONT_SIZE`], used for layout.
+const CHAR_WIDTH: f64 = 6.5;
+const LEGEND_ROW: f64 = 18.0;
+
+/// Line, area, bar, pie or doughnut chart rendered as inline SVG.
+///
+/// The chart scales to the width of its container. Pie and doughnut charts
+/// only show their first dataset.
+#[derive(Debug, Clone)]
+pub struct SvgChart {
+    pub kind: ChartKind,
+    pub labels: Vec<Cow<'static, str>>,
+    pub datasets: Vec<Dataset>,
+    pub format: ValueFormat,
+    /// Show a legend with the dataset labels, or slice labels for pie and doughnut charts.
+    pub legend: bool,
+    /// Size of the drawing, in SVG units.
+    pub width: u32,
+    pub height: u32,
+}
+
+impl SvgChart {
+    pub fn new(kind: ChartKind) -> Self {
+        SvgChart {
+            kind,
+            labels: Vec::new(),
+            datasets: Vec::new(),
+            format: ValueFormat::default(),
+            legend: false,
+            width: 600,
+            height: 300,
+        }
+    }
+
+    pub fn line() -> Self {
+        SvgChart::new(ChartKind::Line)
+    }
+
+    pub fn area() -> Self {
+        SvgChart::new(ChartKind::Area)
+    }
+
+    pub fn bar() -> Self {
+        SvgChart::new(ChartKind::Bar)
+    }
+
+    pub fn horizontal_bar() -> Self {
+        SvgChart::new(ChartKind::HorizontalBar)
+    }
+
+    pub fn pie() -> Self {
+        SvgChart::new(ChartKind::Pie)
+    }
+
+    pub fn doughnut() -> Self {
+        SvgChart::new(ChartKind::Doughnut)
+    }
+
+    /// Labels of the category axis, or of the slices of pie and doughnut charts.
+    pub fn with_labels<S: Into<Cow<'static, str>>, I: IntoIterator<Item = S>>(
+        mut self,
+        labels: I,
+    ) -> Self {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_dataset(mut self, dataset: Dataset) -> Self {
+        self.datasets.push(dataset);
+        self
+    }
+
+    pub fn with_format(mut self, format: ValueFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn with_legend(mut self) -> Self {
+        self.legend = true;
+        self
+    }
+
+    /// Size of the drawing, which determines its aspect ratio and the
+    /// relative size of the text.
+    pub fn with_size(mut self, width: u32, height: u32) -> Self {
+        self.width = width.max(100);
+        self.height = height.max(60);
+        self
+    }
+
+    fn label(&self, index: usize) -> &str {
+        self.labels.get(index).map_or("", |label| label)
+    }
+
+    /// Colored labels shown in the legend.
+    fn legend_entries(&self) -> Vec<(Color, String)> {
+        if !self.legend {
+            return Vec::new();
+        }
+
+        match (self.kind, self.datasets.first()) {
+            (ChartKind::Pie | ChartKind::Doughnut, Some(dataset)) => (0..dataset.values.len())
+                .map(|slice| (dataset.slice_color(slice), self.label(slice).to_string()))
+                .collect(),
+            (ChartKind::Pie | ChartKind::Doughnut, None) => Vec::new(),
+            _ => self
+                .datasets
+                .iter()
+                .enumerate()
+                .map(|(index, dataset)| (dataset.series_color(index), dataset.label.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Positions of the legend entries, wrapped into rows, relative to the
+    /// top left of the legend.
+    fn legend_layout(&self) -> Vec<(f64, f64, Color, String)> {
+        let width = f64::from(self.width);
+        let (mut x, mut y) = (10.0, 0.0);
+        let mut layout = Vec::new();
+
+        for (color, label) in self.legend_entries() {
+            let entry = 18.0 + label.chars().count() as f64 * CHAR_WIDTH + 16.0;
+            if x > 10.0 && x + entry > width - 10.0 {
+                x = 10.0;
+                y += LEGEND_ROW;
+            }
+
+            layout.push((x, y, color, label));
+            x += entry;
+        }
+
+        layout
+    }
+
+    fn write_legend(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        layout: &[(f64, f64, Color, String)],
+        top: f64,
+    ) -> fmt::Result {
+        for (x, y, color, label) in layout {
+            write!(
+                f,
+                r#"<rect x="{}" y="{}" width="12" height="12" rx="2" fill="{}"/><text x="{}" y="{}" dy="0.35em">{}</text>"#,
+                num(*x),
+                num(top + y),
+                rgba(*color, 1.0),
+                num(x + 18.0),
+                num(top + y + 6.0),
+                escape(label)
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn write_series(&self, f: &mut fmt::Formatter<'_>, height: f64) -> fmt::Result {
+        let width = f64::from(self.width);
+        let horizontal = self.kind == ChartKind::HorizontalBar;
+        let bars = matches!(self.kind, ChartKind::Bar | ChartKind::HorizontalBar);
+
+        let values = self
+            .datasets
+            .iter()
+            .flat_map(|dataset| dataset.values.iter().copied())
+            .filter(|value| value.is_finite());
+        let (min, max) = values.fold((0.0_f64, 0.0_f64), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+        let ticks = nice_ticks(min, if max > min { max } else { min + 1.0 });
+        let (low, high) = (ticks[0], ticks[ticks.len() - 1]);
+
+        let count = self
+            .datasets
+            .iter()
+            .map(|dataset| dataset.values.len())
+            .chain([self.labels.len()])
+            .max()
+            .unwrap_or(0)
+            .max(1);
+
+        let longest_label = (0..count)
+            .map(|index| self.label(index).chars().count())
+            .max()
+            .unwrap_or(0) as f64
+            * CHAR_WIDTH;
+        let longest_tick = ticks
+            .iter()
+            .map(|tick| self.format.format(*tick).chars().count())
+            .max()
+            .unwrap_or(0) as f64
+            * CHAR_WIDTH;
+
+        // Room for the labels left of the plot.
+        let left = if horizontal {
+            (longest_label + 12.0).clamp(30.0, width / 3.0)
+        } else {
+            (longest_tick + 12.0).max(30.0)
+        };
+        let (top, right, bottom) = (10.0, 20.0, 24.0);
+        let plot_width = (width - left - right).max(1.0);
+        let plot_height = (height - top - bottom).max(1.0);
+
+        // Position of a value, along the value axis.
+        let value_at = |value: f64| {
+            let ratio = (value - low) / (high - low);
+            if horizontal {
+                left + ratio * plot_width
+            } else {
+                top + plot_height - ratio * plot_height
+            }
+        };
+
+        // Center of a category, along the category axis.
+        let (axis_start, axis_length) = if horizontal {
+            (top, plot_height)
+        } else {
+            (left, plot_width)
+        };
+        let band = axis_length / count as f64;
+        let category_at = |index: usize| {
+            if bars {
+                axis_start + band * (index as f64 + 0.5)
+            } else if count == 1 {
+                axis_start + axis_length / 2.0
+            } else {
+                axis_start + axis_length * index as f64 / (count - 1) as f64
+            }
+        };
+        let point = |index: usize, value: f64| {
+            if horizontal {
+                (value_at(value), category_at(index))
+            } else {
+                (category_at(index), value_at(value))
+            }
+        };
+
+        for tick in &ticks {
+            let position = value_at(*tick);
+            if horizontal {
+                write!(
+                    f,
+                    r#"<line x1="{0}" y1="{1}" x2="{0}" y2="{2}" stroke="{GRID_COLOR}" stroke-dasharray="2"/><text x="{0}" y="{3}" text-anchor="middle">{4}</text>"#,
+                    num(position),
+                    num(top),
+                    num(top + plot_height),
+                    num(top + plot_height + 16.0),
+                    escape(&self.format.format(*tick))
+                )?;
+            } else {
+                write!(
+                    f,
+                    r#"<line x1="{0}" y1="{1}" x2="{2}" y2="{1}" stroke="{GRID_COLOR}" stroke-dasharray="2"/><text x="{3}" y="{1}" dy="0.35em" text-anchor="end">{4}</text>"#,
+                    num(left),
+                    num(position),
+                    num(left + plot_width),
+                    num(left - 6.0),
+                    escape(&self.format.format(*tick))
+                )?;
+            }
+        }
+
+        // Skip category labels which would overlap.
+        let label_size = if horizontal {
+            LEGEND_ROW
+        } else {
+            longest_label + 8.0
+        };
+        let step = ((label_size * count as f64 / axis_length).ceil() as usize).max(1);
+        for index in (0..count).step_by(step) {
+            let position = category_at(index);
+            if horizontal {
+                write!(
+                    f,
+                    r#"<text x="{}" y="{}" dy="0.35em" text-anchor="end">{}</text>"#,
+                    num(left - 6.0),
+                    num(position),
+                    escape(self.label(index))
+                )?;
+            } else {
+                write!(
+                    f,
+                    r#"<text x="{}" y="{}" text-anchor="middle">{}</text>"#,
+                    num(position),
+                    num(top + plot_height + 16.0),
+                    escape(self.label(index))
+                )?;
+            }
+        }
+
+        let zero = value_at(0.0_f64.clamp(low, high));
+        let group = band * 0.8;
+        let bar = group / self.datasets.len().max(1) as f64;
+
+        for (series, dataset) in self.datasets.iter().enumerate() {
+            let color = dataset.series_color(series);
+
+            if bars {
+                for (index, value) in dataset.values.iter().enumerate() {
+                    if !value.is_finite() {
+                        continue;
+                    }
+
+                    let offset = category_at(index) - group / 2.0 + bar * series as f64;
+                    let position = value_at(*value);
+                    let (x, y, w, h) = if horizontal {
+                        (position.min(zero), offset, (position - zero).abs(), bar)
+                    } else {
+                        (offset, position.min(zero), bar, (position - zero).abs())
+                    };
+
+                    write!(
+                        f,
+                        r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}">{}</rect>"#,
+                        num(x),
+                        num(y),
+                        num(w),
+                        num(h),
+                        rgba(color, 1.0),
+                        self.tooltip(index, dataset, *value)
+                    )?;
+                }
+
+                continue;
+            }
+
+            // Lines are interrupted by values which are not finite.
+            let segments = dataset
+                .values
+                .split(|value| !value.is_finite())
+                .scan(0, |start, segment| {
+                    let first = *start;
+                    *start += segment.len() + 1;
+                    Some((first, segment))
+                })
+                .filter(|(_, segment)| !segment.is_empty());
+
+            for (first, segment) in segments {
+                let points: Vec<_> = segment
+                    .iter()
+                    .enumerate()
+                    .map(|(offset, value)| {
+                        let (x, y) = point(first + offset, *value);
+                        format!("{},{}", num(x), num(y))
+                    })
+                    .collect();
+
+                if self.kind == ChartKind::Area {
+                    let (start, _) = point(first, 0.0);
+                    let (end, _) = point(first + segment.len() - 1, 0.0);
+                    write!(
+                        f,
+                        r#"<polygon points="{},{} {} {},{}" fill="{}"/>"#,
+                        num(start),
+                        num(zero),
+                        points.join(" "),
+                        num(end),
+                        num(zero),
+                        rgba(color, 0.1)
+                    )?;
+                }
+
+                write!(
+                    f,
+                    r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="2"/>"#,
+                    points.join(" "),
+                    rgba(color, 1.0)
+                )?;
+            }
+
+            for (index, value) in dataset.values.iter().enumerate() {
+                if value.is_finite() {
+                    let (x, y) = point(index, *value);
+                    write!(
+                        f,
+                        r#"<circle cx="{}" cy="{}" r="3" fill="{}">{}</circle>"#,
+                        num(x),
+                        num(y),
+                        rgba(color, 1.0),
+                        self.tooltip(index, dataset, *value)
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_slices(&self, f: &mut fmt::Formatter<'_>, height: f64) -> fmt::Result {
+        let Some(dataset) = self.datasets.first() else {
+            return Ok(());
+        };
+
+        let values: Vec<f64> = dataset
+            .values
+            .iter()
+            .map(|value| {
+                if value.is_finite() {
+                    value.max(0.0)
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        let total: f64 = values.iter().sum();
+        if total <= 0.0 {
+            return Ok(());
+        }
+
+        let (cx, cy) = (f64::from(self.width) / 2.0, height / 2.0);
+        let outer = (f64::from(self.width).min(height) / 2.0 - 10.0).max(1.0);
+        let inner = if self.kind == ChartKind::Doughnut {
+            outer * 0.6
+        } else {
+            0.0
+        };
+
+        let mut angle = 0.0;
+        for (slice, value) in values.iter().enumerate() {
+            if *value <= 0.0 {
+                continue;
+            }
+
+            let color = rgba(dataset.slice_color(slice), 1.0);
+            let tooltip = Tooltip(format!(
+                "{}: {} ({}%)",
+                self.label(slice),
+                self.format.format(dataset.values[slice]),
+                num(value / total * 100.0)
+            ));
+            let sweep = value / total * std::f64::consts::TAU;
+
+            if sweep >= std::f64::consts::TAU - 1e-9 {
+                // Arcs cannot describe a full circle.
+                write!(
+                    f,
+                    r#"<circle cx="{}" cy="{}" r="{}" fill="{}" stroke="{}" stroke-width="{}">{}</circle>"#,
+                    num(cx),
+                    num(cy),
+                    num((outer + inner) / 2.0),
+                    if inner > 0.0 { "none" } else { &color },
+                    color,
+                    num(if inner > 0.0 { outer - inner } else { 0.0 }),
+                    tooltip
+                )?;
+                break;
+            }
+
+            let large = u8::from(sweep > std::f64::consts::PI);
+            let at = |radius: f64, angle: f64| {
+                // Starting at the top, clockwise.
+                let (sin, cos) = angle.sin_cos();
+                (num(cx + radius * sin), num(cy - radius * cos))
+            };
+            let (x1, y1) = at(outer, angle);
+            let (x2, y2) = at(outer, angle + sweep);
+            let (x3, y3) = at(inner, angle + sweep);
+            let (x4, y4) = at(inner, angle);
+            let outer = num(outer);
+
+            let path = if inner > 0.0 {
+                let inner = num(inner);
+                format!(
+                    "M{x1} {y1}A{outer} {outer} 0 {large} 1 {x2} {y2}L{x3} {y3}A{inner} {inner} 0 {large} 0 {x4} {y4}Z"
+                )
+            } else {
+                format!("M{x4} {y4}L{x1} {y1}A{outer} {outer} 0 {large} 1 {x2} {y2}Z")
+            };
+
+            write!(
+                f,
+                r##"<path d="{path}" fill="{color}" stroke="#fff" stroke-width="1">{tooltip}</path>"##
+            )?;
+            angle += sweep;
+        }
+
+        Ok(())
+    }
+
+    fn tooltip(&self, index: usize, dataset: &Dataset, value: f64) -> Tooltip {
+        Tooltip(format!(
+            "{}\n{}: {}",
+            self.label(index),
+            dataset.label,
+            self.format.format(value)
+        ))
+    }
+}
+
+impl Display for SvgChart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let legend = self.legend_layout();
+        let legend_height = match legend.last() {
+            Some((_, y, _, _)) => y + LEGEND_ROW + 8.0,
+            None => 0.0,
+        };
+        let height = f64::from(self.height);
+        let plot_height = (height - legend_height).max(1.0);
+
+        let description: Vec<_> = self
+            .datasets
+            .iter()
+            .map(|dataset| dataset.label.as_ref())
+            .collect();
+
+        write!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" class="svg-chart" viewBox="0 0 {} {}" width="100%" role="img" aria-label="{}" font-size="{FONT_SIZE}" fill="{TEXT_COLOR}">"#,
+            self.width,
+            self.height,
+            escape(&description.join(", "))
+        )?;
+
+        match self.kind {
+            ChartKind::Pie | ChartKind::Doughnut => self.write_slices(f, plot_height)?,
+            _ => self.write_series(f, plot_height)?,
+        }
+
+        self.write_legend(f, &legend, plot_height + 4.0)?;
+        f.write_str("</svg>")
+    }
+}
+
+impl From<Chart> for SvgChart {
+    /// Render the same labels and datasets on the server instead.
+    fn from(chart: Chart) -> Self {
+        SvgChart {
+            kind: chart.kind,
+            labels: chart.labels,
+            datasets: chart.datasets,
+            format: chart.format,
+            legend: chart.legend,
+            ..SvgChart::new(chart.kind)
+        }
+    }
+}
+
+/// Small line chart without axes, such as for a [`StatCard`](crate::card::StatCard).
+#[derive(Debug, Clone)]
+pub struct Sparkline {
+    pub values: Vec<f64>,
+    pub color: Color,
+    /// Fill the area below the line.
+    pub fill: bool,
+    /// Describes the values, shown in the tooltip.
+    pub label: Option<Cow<'static, str>>,
+    pub format: ValueFormat,
+    /// Size in pixels.
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Sparkline {
+    pub fn new<V: Into<f64>, I: IntoIterator<Item = V>>(values: I) -> Self {
+        Sparkline {
+            values: values.into_iter().map(Into::into).collect(),
+            color: Color::Primary,
+            fill: false,
+            label: None,
+            format: ValueFormat::default(),
+            width: 120,
+            height: 32,
+        }
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn filled(mut self) -> Self {
+        self.fill = true;
+        self
+    }
+
+    pub fn with_label<S: Into<Cow<'static, str>>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn with_format(mut self, format: ValueFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn with_size(mut self, width: u32, height: u32) -> Self {
+        self.width = width.max(10);
+        self.height = height.max(10);
+        self
+    }
+}
+
+impl Display for Sparkline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let values: Vec<f64> = self
+            .values
+            .iter()
+            .copied()
+            .filter(|value| value.is_finite())
+            .collect();
+        let (width, height) = (f64::from(self.width), f64::from(self.height));
+
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let summary = match values.first() {
+            Some(_) => format!("{} – {}", self.format.format(min), self.format.format(max)),
+            None => String::new(),
+        };
+        let title = match &self.label {
+            Some(label) if !summary.is_empty() => format!("{label}: {summary}"),
+            Some(label) => label.to_string(),
+            None => summary,
+        };
+
+        write!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" class="sparkline" width="{0}" height="{1}" viewBox="0 0 {0} {1}" role="img" aria-label="{2}"><title>{2}</title>"#,
+            self.width,
+            self.height,
+            escape(&title)
+        )?;
+
+        // Keep the line and the last point within the drawing.
+        let pad = 3.0;
+        let range = if max > min { max - min } else { 1.0 };
+        let points: Vec<(f64, f64)> = values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let x = if values.len() > 1 {
+                    pad + (width - 2.0 * pad) * index as f64 / (values.len() - 1) as f64
+                } else {
+                    width / 2.0
+                };
+                let y = if max > min {
+                    pad + (height - 2.0 * pad) * (max - value) / range
+                } else {
+                    height / 2.0
+                };
+                (x, y)
+            })
+            .collect();
+
+        if let (Some(first), Some(last)) = (points.first(), points.last()) {
+            let line: Vec<_> = points
+                .iter()
+                .map(|(x, y)| format!("{},{}", num(*x), num(*y)))
+                .collect();
+
+            if self.fill {
+                write!(
+                    f,
+                    r#"<polygon points="{},{} {} {},{}" fill="{}"/>"#,
+                    num(first.0),
+                    num(height),
+                    line.join(" "),
+                    num(last.0),
+                    num(height),
+                    rgba(self.color, 0.15)
+                )?;
+            }
+
+            write!(
+                f,
+                r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="1.5" stroke-linejoin="round"/><circle cx="{}" cy="{}" r="2" fill="{1}">{}</circle>"#,
+                line.join(" "),
+                rgba(self.color, 1.0),
+                num(last.0),
+                num(last.1),
+                Tooltip(self.format.format(values[values.len() - 1]))
+            )?;
+        }
+
+        f.write_str("</svg>")
+    }
+}
+
+/// `<title>` element, shown as a tooltip by browsers.
+struct Tooltip(String);
+
+impl Display for Tooltip {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<title>{}</title>", escape(&self.0))
+    }
+}
+
+fn escape(value: &str) -> MarkupDisplay<Html, &str> {
+    MarkupDisplay::new_unsafe(value, Html)
+}
+
+/// Coordinate rounded to two decimals, so that output does not depend on
+/// floating point noise.
+fn num(value: f64) -> String {
+    let rounded = (value * 100.0).round() / 100.0;
+    if rounded == 0.0 {
+        String::from("0")
+    } else {
+        rounded.to_string()
+    }
+}
+
+/// Evenly spaced round values covering `min..=max`, such as `0, 25, 50, 75, 100`.
+fn nice_ticks(min: f64, max: f64) -> Vec<f64> {
+    let raw = (max - min) / 4.0;
+    let magnitude = 10_f64.powf(raw.log10().floor());
+    let step = [1.0, 2.0, 2.5, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .find(|step| *step >= raw)
+        .unwrap_or(10.0 * magnitude);
+
+    let start = (min / step).floor();
+    let end = (max / step).ceil();
+    // Enough decimals to represent the step exactly.
+    let decimals = (-step.log10().floor()).max(0.0) as usize + 1;
+
+    (0..=(end - start) as usize)
+        .map(|index| {
+            format!("{:.*}", decimals, (start + index as f64) * step)
+                .parse()
+                .unwrap_or(0.0)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{nice_ticks, Sparkline, SvgChart};
+    use crate::chart::Dataset;
+
+    #[test]
+    fn ticks() {
+        assert_eq!(nice_ticks(0.0, 100.0), [0.0, 25.0, 50.0, 75.0, 100.0]);
+        assert_eq!(nice_ticks(-3.0, 7.0), [-5.0, -2.5, 0.0, 2.5, 5.0, 7.5]);
+        assert_eq!(nice_ticks(0.0, 0.3), [0.0, 0.1, 0.2, 0.3]);
+    }
+
+    #[test]
+    fn render_sparkline() {
+        assert_eq!(
+            Sparkline::new([1.0, 3.0, 2.0])
+                .with_label("Load")
+                .with_size(46, 16)
+                .to_string(),
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" class="sparkline" width="46" height="16" viewBox="0 0 46 16" role="img" aria-label="Load: 1 – 3"><title>Load: 1 – 3</title>"#,
+                r#"<polyline points="3,13 23,3 43,8" fill="none" stroke="rgba(78, 115, 223, 1)" stroke-width="1.5" stroke-linejoin="round"/>"#,
+                r#"<circle cx="43" cy="8" r="2" fill="rgba(78, 115, 223, 1)"><title>2</title></circle></svg>"#
+            )
+        );
+    }
+
+    #[test]
+    fn render_charts() {
+        let pie = SvgChart::doughnut()
+            .with_labels(["<a>", "b"])
+            .with_dataset(Dataset::new("Share", [1.0, 3.0]))
+            .with_legend();
+        let rendered = pie.to_string();
+
+        assert_eq!(rendered, pie.to_string());
+        assert!(rendered.contains("<title>&lt;a&gt;: 1 (25%)</title>"));
+        assert_eq!(rendered.matches("<path").count(), 2);
+
+        let line = SvgChart::line()
+            .with_labels(["a", "b", "c"])
+            .with_dataset(Dataset::new("Gap", [1.0, f64::NAN, 2.0]))
+            .to_string();
+
+        assert_eq!(line.matches("<polyline").count(), 2);
+        assert_eq!(line.matches("<circle").count(), 2);
+    }
+}
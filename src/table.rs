@@ -0,0 +1,1202 @@
+//! Tables built from typed columns, with server-driven sorting, search and
+//! pagination.
+//!
+//! The server renders only the current page of rows, as described by the
+//! [`TableQuery`] of the request. Sort links, the search input and the
+//! pagination controls re-request the table at its URL using htmx, swapping
+//! in only the table body, header and pagination, so the search input keeps
+//! its focus.
+//!
+//! ```
+//! use bootstrap_dashboard::table::{Alignment, Table, TableColumn, TableQuery};
+//!
+//! struct User {
+//!     name: String,
+//!     logins: u32,
+//! }
+//!
+//! let query = TableQuery::from_query_string("sort=logins&order=desc");
+//! let users = vec![User { name: "Jane".into(), logins: 42 }];
+//!
+//! let table = Table::new("users", "/users")
+//!     .with_column(TableColumn::new("name", "Name", |user: &User| user.name.clone()).sortable())
+//!     .with_column(
+//!         TableColumn::new("logins", "Logins", |user: &User| user.logins)
+//!             .with_align(Alignment::Right)
+//!             .sortable(),
+//!     )
+//!     .with_rows(users)
+//!     .with_query(query)
+//!     .with_pagination(1, 25)
+//!     .searchable()
+//!     .hover();
+//!
+//! assert!(table.to_string().contains("Jane"));
+//! ```
+use std::{borrow::Cow, fmt::Display};
+
+use askama::{Html, MarkupDisplay, Template};
+
+#[cfg(feature = "derive")]
+pub use bootstrap_dashboard_derive::DashboardTable;
+
+use crate::{
+    card::CardButton,
+    htmx::{EventModifier, HxSwap, SwapTarget, Target, TriggerEvent},
+    icons,
+    markup::json_string,
+    route::{decode_component, encode_component},
+    Icon, LinkAction,
+};
+
+/// Horizontal alignment of the cells of a [`TableColumn`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Alignment {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+impl AsRef<str> for Alignment {
+    fn as_ref(&self) -> &str {
+        match self {
+            Alignment::Left => "text-left",
+            Alignment::Center => "text-center",
+            Alignment::Right => "text-right",
+        }
+    }
+}
+
+impl Display for Alignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+type Renderer<Row> = Box<dyn Fn(&Row) -> String + Send + Sync>;
+
+/// Column of a [`Table`], rendering one cell per row.
+pub struct TableColumn<Row> {
+    /// Identifies the column in the [`TableQuery::sort`] parameter.
+    pub key: Cow<'static, str>,
+    pub header: Cow<'static, str>,
+    pub align: Alignment,
+    pub sortable: bool,
+    /// Hidden columns are not shown in the [`Table`], only in [`Details`].
+    pub hidden: bool,
+    render: Renderer<Row>,
+    escape: bool,
+    export: ExportValue<Row>,
+}
+
+/// Value of a [`TableColumn`] in exports, see [`Table::with_export`].
+enum ExportValue<Row> {
+    /// The unescaped value returned by the column's renderer.
+    Rendered,
+    Custom(Box<dyn Fn(&Row) -> CellValue + Send + Sync>),
+    Excluded,
+}
+
+/// Plain value of a cell in exports, keeping its type in JSON exports.
+///
+/// Converted from strings, numbers, booleans and options of these, see
+/// [`TableColumn::with_export`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellValue {
+    Text(String),
+    /// Formatted number, written as-is.
+    Number(String),
+    Bool(bool),
+    /// Missing value, also used for non-finite floats.
+    Null,
+}
+
+impl From<String> for CellValue {
+    fn from(value: String) -> Self {
+        CellValue::Text(value)
+    }
+}
+
+impl From<&str> for CellValue {
+    fn from(value: &str) -> Self {
+        CellValue::Text(value.to_string())
+    }
+}
+
+impl From<&String> for CellValue {
+    fn from(value: &String) -> Self {
+        CellValue::Text(value.clone())
+    }
+}
+
+impl From<Cow<'_, str>> for CellValue {
+    fn from(value: Cow<'_, str>) -> Self {
+        CellValue::Text(value.into_owned())
+    }
+}
+
+impl From<char> for CellValue {
+    fn from(value: char) -> Self {
+        CellValue::Text(value.to_string())
+    }
+}
+
+impl From<bool> for CellValue {
+    fn from(value: bool) -> Self {
+        CellValue::Bool(value)
+    }
+}
+
+macro_rules! integer_cell_values {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for CellValue {
+                fn from(value: $integer) -> Self {
+                    CellValue::Number(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+integer_cell_values!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl From<f32> for CellValue {
+    fn from(value: f32) -> Self {
+        CellValue::from(f64::from(value))
+    }
+}
+
+impl From<f64> for CellValue {
+    fn from(value: f64) -> Self {
+        if value.is_finite() {
+            CellValue::Number(value.to_string())
+        } else {
+            CellValue::Null
+        }
+    }
+}
+
+impl<T: Into<CellValue>> From<Option<T>> for CellValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(CellValue::Null, Into::into)
+    }
+}
+
+impl CellValue {
+    fn csv(&self) -> Cow<'_, str> {
+        match self {
+            CellValue::Text(text) => csv_field(text),
+            CellValue::Number(number) => number.as_str().into(),
+            CellValue::Bool(value) => value.to_string().into(),
+            CellValue::Null => "".into(),
+        }
+    }
+
+    fn json(&self) -> Cow<'_, str> {
+        match self {
+            CellValue::Text(text) => json_string(text).into(),
+            CellValue::Number(number) => number.as_str().into(),
+            CellValue::Bool(value) => value.to_string().into(),
+            CellValue::Null => "null".into(),
+        }
+    }
+}
+
+impl<Row> TableColumn<Row> {
+    /// Column whose cells display the value returned by `render`, escaped.
+    pub fn new<K, H, D, F>(key: K, header: H, render: F) -> Self
+    where
+        K: Into<Cow<'static, str>>,
+        H: Into<Cow<'static, str>>,
+        D: Display,
+        F: Fn(&Row) -> D + Send + Sync + 'static,
+    {
+        TableColumn {
+            key: key.into(),
+            header: header.into(),
+            align: Alignment::Left,
+            sortable: false,
+            hidden: false,
+            render: Box::new(move |row| render(row).to_string()),
+            escape: true,
+            export: ExportValue::Rendered,
+        }
+    }
+
+    /// Column whose cells contain the markup returned by `render`, such as
+    /// links or badges, rendered as-is.
+    ///
+    /// Markup columns are left out of exports, unless given a plain value
+    /// using [`TableColumn::with_export`].
+    pub fn markup<K, H, D, F>(key: K, header: H, render: F) -> Self
+    where
+        K: Into<Cow<'static, str>>,
+        H: Into<Cow<'static, str>>,
+        D: Display,
+        F: Fn(&Row) -> D + Send + Sync + 'static,
+    {
+        TableColumn {
+            escape: false,
+            export: ExportValue::Excluded,
+            ..TableColumn::new(key, header, render)
+        }
+    }
+
+    /// Column whose cells link to the URL returned by `href`, labeled with
+    /// the escaped value returned by `render`.
+    ///
+    /// Only the label is exported.
+    pub fn link<K, H, U, D, FU, FD>(key: K, header: H, href: FU, render: FD) -> Self
+    where
+        K: Into<Cow<'static, str>>,
+        H: Into<Cow<'static, str>>,
+        U: Display,
+        D: Display,
+        FU: Fn(&Row) -> U + Send + Sync + 'static,
+        FD: Fn(&Row) -> D + Send + Sync + 'static,
+    {
+        let render = std::sync::Arc::new(render);
+        let label = render.clone();
+
+        TableColumn::markup(key, header, move |row: &Row| {
+            format!(
+                r#"<a href="{}">{}</a>"#,
+                MarkupDisplay::new_unsafe(href(row), Html),
+                MarkupDisplay::new_unsafe(render(row), Html)
+            )
+        })
+        .with_export(move |row: &Row| label(row).to_string())
+    }
+
+    pub fn with_align(mut self, align: Alignment) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+
+    /// Allow sorting the table by this column, by clicking its header.
+    pub fn sortable(mut self) -> Self {
+        self.sortable = true;
+        self
+    }
+
+    /// Export the value returned by `export` instead of the rendered one.
+    ///
+    /// Numbers and booleans stay typed in JSON exports, whereas rendered
+    /// values are always exported as strings.
+    pub fn with_export<D, F>(mut self, export: F) -> Self
+    where
+        D: Into<CellValue>,
+        F: Fn(&Row) -> D + Send + Sync + 'static,
+    {
+        self.export = ExportValue::Custom(Box::new(move |row| export(row).into()));
+        self
+    }
+
+    /// Leave this column out of exports.
+    pub fn without_export(mut self) -> Self {
+        self.export = ExportValue::Excluded;
+        self
+    }
+
+    /// Whether this column is included in exports.
+    pub fn exported(&self) -> bool {
+        !matches!(self.export, ExportValue::Excluded)
+    }
+
+    /// Plain value of the cell for the given row, used in exports.
+    pub fn export(&self, row: &Row) -> Option<CellValue> {
+        match &self.export {
+            ExportValue::Rendered => Some(CellValue::Text((self.render)(row))),
+            ExportValue::Custom(export) => Some(export(row)),
+            ExportValue::Excluded => None,
+        }
+    }
+
+    /// Markup of the cell for the given row.
+    pub fn render(&self, row: &Row) -> String {
+        let value = (self.render)(row);
+
+        if self.escape {
+            MarkupDisplay::new_unsafe(value, Html).to_string()
+        } else {
+            value
+        }
+    }
+}
+
+/// File format of a [`Table`] export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma-separated values, with a header line.
+    Csv,
+    /// Array of objects, keyed by [`TableColumn::key`].
+    Json,
+}
+
+impl ExportFormat {
+    /// Value of the [`TableQuery::EXPORT_PARAM`] parameter, as well as the file extension.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Json => "application/json",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "CSV",
+            ExportFormat::Json => "JSON",
+        }
+    }
+
+    fn icon(&self) -> Icon {
+        match self {
+            ExportFormat::Csv => icons::fa::FILE_CSV,
+            ExportFormat::Json => icons::fa::FILE_CODE,
+        }
+    }
+
+    fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "csv" => Some(ExportFormat::Csv),
+            "json" => Some(ExportFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Current sorting, search and page of a [`Table`], parsed from the query string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableQuery {
+    /// [`TableColumn::key`] of the column to sort by.
+    pub sort: Option<String>,
+    pub descending: bool,
+    pub search: Option<String>,
+    /// Current page, starting at 1.
+    pub page: usize,
+    /// Requested export, in which case all matching rows should be given
+    /// to the [`Table`], see [`Table::into_export`].
+    pub export: Option<ExportFormat>,
+}
+
+impl Default for TableQuery {
+    fn default() -> Self {
+        TableQuery {
+            sort: None,
+            descending: false,
+            search: None,
+            page: 1,
+            export: None,
+        }
+    }
+}
+
+impl TableQuery {
+    pub const SORT_PARAM: &'static str = "sort";
+    /// Either `asc` or `desc`.
+    pub const ORDER_PARAM: &'static str = "order";
+    pub const SEARCH_PARAM: &'static str = "search";
+    pub const PAGE_PARAM: &'static str = "page";
+    /// Either `csv` or `json`, see [`ExportFormat`].
+    pub const EXPORT_PARAM: &'static str = "export";
+
+    /// Parse the query from a URL query string, such as `sort=name&order=desc&page=2`.
+    ///
+    /// Unknown parameters are ignored, as are invalid values.
+    pub fn from_query_string(query: &str) -> Self {
+        let mut parsed = TableQuery::default();
+
+        for (name, value) in query
+            .trim_start_matches('?')
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+        {
+            let value = decode_component(value);
+
+            match name {
+                Self::SORT_PARAM if !value.is_empty() => parsed.sort = Some(value.into_owned()),
+                Self::ORDER_PARAM => parsed.descending = value == "desc",
+                Self::SEARCH_PARAM if !value.trim().is_empty() => {
+                    parsed.search = Some(value.trim().to_string())
+                }
+                Self::PAGE_PARAM => parsed.page = value.parse().unwrap_or(1).max(1),
+                Self::EXPORT_PARAM => parsed.export = ExportFormat::from_extension(&value),
+                _ => {}
+            }
+        }
+
+        parsed
+    }
+
+    /// Index of the first row of the current page.
+    pub fn offset(&self, page_size: usize) -> usize {
+        (self.page - 1) * page_size
+    }
+
+    /// Query sorting by the given column, toggling the order if it already is,
+    /// and returning to the first page.
+    pub fn sorted_by(&self, key: &str) -> Self {
+        let toggle = self.sort.as_deref() == Some(key);
+
+        TableQuery {
+            sort: Some(key.to_string()),
+            descending: toggle && !self.descending,
+            search: self.search.clone(),
+            page: 1,
+            export: None,
+        }
+    }
+
+    pub fn with_page(&self, page: usize) -> Self {
+        TableQuery {
+            page: page.max(1),
+            export: None,
+            ..self.clone()
+        }
+    }
+
+    /// Query exporting all rows matching the search, in the current order.
+    pub fn with_export(&self, format: ExportFormat) -> Self {
+        TableQuery {
+            page: 1,
+            export: Some(format),
+            ..self.clone()
+        }
+    }
+
+    /// Query string parameters, omitting default values.
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+
+        if let Some(sort) = &self.sort {
+            params.push((Self::SORT_PARAM, sort.clone()));
+            params.push((
+                Self::ORDER_PARAM,
+                if self.descending { "desc" } else { "asc" }.to_string(),
+            ));
+        }
+
+        if let Some(search) = &self.search {
+            params.push((Self::SEARCH_PARAM, search.clone()));
+        }
+
+        if self.page > 1 {
+            params.push((Self::PAGE_PARAM, self.page.to_string()));
+        }
+
+        if let Some(export) = &self.export {
+            params.push((Self::EXPORT_PARAM, export.extension().to_string()));
+        }
+
+        params
+    }
+
+    /// The given URL with this query appended.
+    pub fn url(&self, base: &str) -> String {
+        let mut url = base.to_string();
+
+        for (key, value) in self.params() {
+            url.push(if url.contains('?') { '&' } else { '?' });
+            url.push_str(key);
+            url.push('=');
+            url.push_str(&encode_component(&value));
+        }
+
+        url
+    }
+}
+
+#[cfg(feature = "axum")]
+mod axum_query {
+    use std::convert::Infallible;
+
+    use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+
+    use super::TableQuery;
+
+    /// Extracts the [`TableQuery`] from the request's query string.
+    #[async_trait]
+    impl<S: Send + Sync> FromRequestParts<S> for TableQuery {
+        type Rejection = Infallible;
+
+        async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+            Ok(TableQuery::from_query_string(
+                parts.uri.query().unwrap_or_default(),
+            ))
+        }
+    }
+}
+
+/// Types shown as [`Table`] rows, with one column per field.
+///
+/// Usually implemented using `#[derive(DashboardTable)]`, available with
+/// the `derive` feature. Fields can be configured using `#[table(...)]`:
+///
+/// * `label = "..."` replaces the header, which defaults to the field name in sentence case.
+/// * `format = "..."` formats the value using [`format!`], such as `"{:.2}"`.
+/// * `link = "..."` links the value to a URL containing other fields, such as
+///   `"/users/{id}"`. Field values are percent-encoded using
+///   [`encode_component`](crate::route::encode_component).
+/// * `align = "left" | "center" | "right"`.
+/// * `markup` renders the value without escaping it.
+/// * `sortable` allows sorting by the field, with the field name as [`TableQuery::sort`] key.
+/// * `hidden` only shows the field in the [`Details`] view.
+/// * `skip` excludes the field entirely.
+///
+/// `Option` fields are rendered empty when `None`. Numbers and `bool`s without
+/// `format`, `link` or `markup` stay typed in JSON exports, see
+/// [`TableColumn::with_export`].
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use bootstrap_dashboard::{card::Card, table::DashboardTable};
+///
+/// #[derive(DashboardTable)]
+/// struct User {
+///     #[table(label = "#", link = "/users/{id}", sortable)]
+///     id: u32,
+///     #[table(sortable)]
+///     display_name: String,
+///     #[table(format = "{:.1}%", align = "right")]
+///     quota_used: f32,
+///     #[table(hidden)]
+///     email: Option<String>,
+///     #[table(skip)]
+///     password_hash: String,
+/// }
+///
+/// let user = User {
+///     id: 7,
+///     display_name: "Jane".into(),
+///     quota_used: 12.34,
+///     email: None,
+///     password_hash: String::new(),
+/// };
+///
+/// let detail = Card::new(user.details()).with_header("User").to_string();
+/// assert!(detail.contains("12.3%"));
+///
+/// let table = User::table("users", "/users").with_rows([user]).to_string();
+/// assert!(table.contains(r#"<a href="/users/7">7</a>"#));
+/// assert!(!table.contains("Email"));
+/// # }
+/// ```
+pub trait DashboardTable: Sized {
+    fn columns() -> Vec<TableColumn<Self>>;
+
+    /// Empty [`Table`] with the columns of this type.
+    fn table<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(
+        id: S1,
+        url: S2,
+    ) -> Table<Self> {
+        Self::columns()
+            .into_iter()
+            .fold(Table::new(id, url), Table::with_column)
+    }
+
+    /// Key-value view of all columns, including hidden ones, such as for
+    /// showing a single record in a [`Card`](crate::card::Card).
+    fn details(&self) -> Details {
+        Details(
+            Self::columns()
+                .into_iter()
+                .map(|column| (column.header.clone(), column.render(self)))
+                .collect(),
+        )
+    }
+}
+
+/// Labeled values of a single row, see [`DashboardTable::details`].
+#[derive(Debug, Clone, Template)]
+#[template(
+    ext = "html",
+    source = r#"
+<dl class="row mb-0">
+    {% for (label, value) in self.0 %}
+    <dt class="col-sm-4">{{ label }}</dt>
+    <dd class="col-sm-8">{{ value|safe }}</dd>
+    {% endfor %}
+</dl>
+"#
+)]
+pub struct Details(pub Vec<(Cow<'static, str>, String)>);
+
+/// Table of rows, rendered using typed [`TableColumn`]s.
+///
+/// Only the rows of the current page should be given, see [`TableQuery`].
+#[derive(Template)]
+#[template(path = "table.html")]
+pub struct Table<Row> {
+    /// Must be the same across requests, as it identifies the parts to swap.
+    pub id: Cow<'static, str>,
+    /// URL rendering this table, requested when sorting, searching or paginating.
+    pub url: Cow<'static, str>,
+    columns: Vec<TableColumn<Row>>,
+    rows: Vec<Row>,
+    query: TableQuery,
+    pagination: Option<Pagination>,
+    exports: Vec<ExportFormat>,
+    searchable: bool,
+    striped: bool,
+    bordered: bool,
+    hover: bool,
+    small: bool,
+}
+
+struct Pagination {
+    total: usize,
+    page_size: usize,
+}
+
+impl<Row> Table<Row> {
+    pub fn new<S1: Into<Cow<'static, str>>, S2: Into<Cow<'static, str>>>(id: S1, url: S2) -> Self {
+        Table {
+            id: id.into(),
+            url: url.into(),
+            columns: Vec::new(),
+            rows: Vec::new(),
+            query: TableQuery::default(),
+            pagination: None,
+            exports: Vec::new(),
+            searchable: false,
+            striped: false,
+            bordered: false,
+            hover: false,
+            small: false,
+        }
+    }
+
+    pub fn with_column(mut self, column: TableColumn<Row>) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    pub fn with_rows<I: IntoIterator<Item = Row>>(mut self, rows: I) -> Self {
+        self.rows.extend(rows);
+        self
+    }
+
+    /// The query used to select the rows, shown as the current sorting, search and page.
+    pub fn with_query(mut self, query: TableQuery) -> Self {
+        self.query = query;
+        self
+    }
+
+    /// Show pagination controls, given the `total` number of rows matching
+    /// the search and the number of rows per page.
+    pub fn with_pagination(mut self, total: usize, page_size: usize) -> Self {
+        self.pagination = Some(Pagination {
+            total,
+            page_size: page_size.max(1),
+        });
+        self
+    }
+
+    /// Offer downloading all rows matching the current search, in the
+    /// current order, using [`Table::export_buttons`].
+    ///
+    /// The export is requested from the table's URL, with [`TableQuery::export`]
+    /// set, and should be answered using [`Table::into_export`]. It contains
+    /// every column with an [exported](TableColumn::exported) value, including
+    /// hidden ones.
+    pub fn with_export(mut self, format: ExportFormat) -> Self {
+        if !self.exports.contains(&format) {
+            self.exports.push(format);
+        }
+        self
+    }
+
+    /// Show a search input, submitted as [`TableQuery::search`].
+    pub fn searchable(mut self) -> Self {
+        self.searchable = true;
+        self
+    }
+
+    pub fn striped(mut self) -> Self {
+        self.striped = true;
+        self
+    }
+
+    pub fn bordered(mut self) -> Self {
+        self.bordered = true;
+        self
+    }
+
+    pub fn hover(mut self) -> Self {
+        self.hover = true;
+        self
+    }
+
+    pub fn small(mut self) -> Self {
+        self.small = true;
+        self
+    }
+
+    /// Download buttons for each [`Table::with_export`] format, such as for
+    /// a [`Card`](crate::card::Card) header, see
+    /// [`Card::with_export_buttons`](crate::card::Card::with_export_buttons).
+    ///
+    /// They are kept up to date when sorting or searching, as long as the
+    /// response also contains them.
+    pub fn export_buttons(&self) -> Vec<CardButton> {
+        self.exports
+            .iter()
+            .map(|format| {
+                CardButton::new(format.label())
+                    .with_id(self.export_id(format))
+                    .with_icon(format.icon())
+                    .with_outline()
+                    .with_action(LinkAction::download_as(
+                        self.query.with_export(*format).url(&self.url),
+                        export_filename(&self.id, *format),
+                    ))
+            })
+            .collect()
+    }
+
+    /// All rows, for download in the given format.
+    ///
+    /// Use [`TableExport::with_rows`] to export rows which are not kept in
+    /// memory instead.
+    pub fn into_export(mut self, format: ExportFormat) -> TableExport<Row> {
+        let rows = std::mem::take(&mut self.rows);
+
+        TableExport {
+            table: self,
+            format,
+            rows: rows.into_iter(),
+            encoded: None,
+            done: false,
+        }
+    }
+
+    fn export_id(&self, format: &ExportFormat) -> String {
+        format!("{}-export-{}", self.id, format.extension())
+    }
+
+    fn visible_columns(&self) -> Vec<&TableColumn<Row>> {
+        self.columns
+            .iter()
+            .filter(|column| !column.hidden)
+            .collect()
+    }
+
+    fn classes(&self) -> String {
+        let mut classes = String::from("table");
+
+        for (enabled, class) in [
+            (self.striped, " table-striped"),
+            (self.bordered, " table-bordered"),
+            (self.hover, " table-hover"),
+            (self.small, " table-sm"),
+        ] {
+            if enabled {
+                classes.push_str(class);
+            }
+        }
+
+        classes
+    }
+
+    /// Request swapping in the body, header, pagination and export buttons
+    /// of the table at `url`.
+    fn hx(&self, url: String) -> HxSwap {
+        self.exports
+            .iter()
+            .fold(
+                HxSwap::get(url)
+                    .with_target(Target::Specific(format!("#{}-body", self.id).into()))
+                    .with_select(format!("#{}-body", self.id))
+                    .with_select_oob(format!("#{}-head", self.id))
+                    .with_select_oob(format!("#{}-pagination", self.id)),
+                |hx, format| hx.with_select_oob(format!("#{}", self.export_id(format))),
+            )
+            .with_swap(SwapTarget::OuterHtml)
+    }
+
+    fn search_hx(&self) -> HxSwap {
+        self.hx(self.url.to_string())
+            .with_trigger(
+                TriggerEvent::KeyUp
+                    .with_modifier(EventModifier::Changed)
+                    .with_modifier(EventModifier::Delay(std::time::Duration::from_millis(300))),
+            )
+            .with_trigger(TriggerEvent::Custom("search"))
+            .with_include(format!("#{}-state input", self.id))
+    }
+
+    fn sort_url(&self, column: &TableColumn<Row>) -> String {
+        self.query.sorted_by(&column.key).url(&self.url)
+    }
+
+    fn sort_icon(&self, column: &TableColumn<Row>) -> &'static str {
+        match (
+            self.query.sort.as_deref() == Some(&*column.key),
+            self.query.descending,
+        ) {
+            (false, _) => "fa-sort text-gray-400",
+            (true, false) => "fa-sort-up",
+            (true, true) => "fa-sort-down",
+        }
+    }
+
+    fn page_url(&self, page: &usize) -> String {
+        self.query.with_page(*page).url(&self.url)
+    }
+
+    fn is_current(&self, page: &usize) -> bool {
+        *page == self.query.page
+    }
+
+    fn previous_url(&self) -> String {
+        self.page_url(&self.query.page.saturating_sub(1))
+    }
+
+    fn next_url(&self) -> String {
+        self.page_url(&(self.query.page + 1))
+    }
+
+    fn page_count(&self) -> usize {
+        self.pagination.as_ref().map_or(1, |pagination| {
+            pagination.total.div_ceil(pagination.page_size).max(1)
+        })
+    }
+
+    /// Pages to link to: the first and last, and those around the current
+    /// one, with `None` marking the gaps in between.
+    fn pages(&self) -> Vec<Option<usize>> {
+        let count = self.page_count();
+        let current = self.query.page;
+        let mut pages = Vec::new();
+
+        for page in 1..=count {
+            if page == 1 || page == count || page.abs_diff(current) <= 2 {
+                pages.push(Some(page));
+            } else if pages.last() != Some(&None) {
+                pages.push(None);
+            }
+        }
+
+        pages
+    }
+}
+
+/// Rows of a [`Table`] encoded as CSV or JSON, see [`Table::into_export`].
+///
+/// Iterates over the encoded export: the header, one piece per row and the
+/// footer. Rows are only taken from the iterator given to
+/// [`TableExport::with_rows`] as they are encoded, so large exports need not
+/// be loaded at once. With the `axum` feature, it can be returned as a
+/// streamed download.
+pub struct TableExport<Row, Rows = std::vec::IntoIter<Row>> {
+    table: Table<Row>,
+    format: ExportFormat,
+    rows: Rows,
+    /// Rows encoded so far, or `None` before the header.
+    encoded: Option<usize>,
+    done: bool,
+}
+
+impl<Row, Rows> TableExport<Row, Rows> {
+    pub fn format(&self) -> ExportFormat {
+        self.format
+    }
+
+    /// Name of the downloaded file, based on the table id.
+    pub fn filename(&self) -> String {
+        export_filename(&self.table.id, self.format)
+    }
+
+    /// Export the given rows instead of those of the table, such as rows
+    /// fetched from a database cursor one at a time.
+    pub fn with_rows<I: IntoIterator<Item = Row>>(self, rows: I) -> TableExport<Row, I::IntoIter> {
+        TableExport {
+            table: self.table,
+            format: self.format,
+            rows: rows.into_iter(),
+            encoded: None,
+            done: false,
+        }
+    }
+
+    fn columns(&self) -> impl Iterator<Item = &TableColumn<Row>> {
+        self.table.columns.iter().filter(|column| column.exported())
+    }
+
+    fn header(&self) -> String {
+        match self.format {
+            ExportFormat::Csv => {
+                let headers: Vec<_> = self
+                    .columns()
+                    .map(|column| csv_field(&column.header))
+                    .collect();
+                format!("{}\r\n", headers.join(","))
+            }
+            ExportFormat::Json => String::from("["),
+        }
+    }
+
+    fn row(&self, row: &Row, index: usize) -> String {
+        let values = self
+            .columns()
+            .map(|column| (column, column.export(row).unwrap_or(CellValue::Null)));
+
+        match self.format {
+            ExportFormat::Csv => {
+                let fields: Vec<_> = values.map(|(_, value)| value.csv().into_owned()).collect();
+                format!("{}\r\n", fields.join(","))
+            }
+            ExportFormat::Json => {
+                let fields: Vec<_> = values
+                    .map(|(column, value)| {
+                        format!("{}: {}", json_string(&column.key), value.json())
+                    })
+                    .collect();
+                let separator = if index == 0 { "\n  " } else { ",\n  " };
+                format!("{separator}{{{}}}", fields.join(", "))
+            }
+        }
+    }
+}
+
+impl<Row, Rows: Iterator<Item = Row>> Iterator for TableExport<Row, Rows> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.done {
+            return None;
+        }
+
+        let Some(index) = self.encoded else {
+            self.encoded = Some(0);
+            return Some(self.header());
+        };
+
+        match self.rows.next() {
+            Some(row) => {
+                self.encoded = Some(index + 1);
+                Some(self.row(&row, index))
+            }
+            None => {
+                self.done = true;
+                match self.format {
+                    ExportFormat::Csv => None,
+                    ExportFormat::Json if index == 0 => Some(String::from("]\n")),
+                    ExportFormat::Json => Some(String::from("\n]\n")),
+                }
+            }
+        }
+    }
+}
+
+fn export_filename(id: &str, format: ExportFormat) -> String {
+    let name: String = id
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => c,
+            _ => '_',
+        })
+        .collect();
+
+    format!("{name}.{}", format.extension())
+}
+
+/// Quote the value if it contains separators, quotes or line breaks.
+///
+/// Values which spreadsheets would evaluate as formulas are prefixed with
+/// `'`, so opening an export cannot run commands or leak data.
+fn csv_field(value: &str) -> Cow<'_, str> {
+    let value: Cow<'_, str> = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{value}").into()
+    } else {
+        value.into()
+    };
+
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\"")).into()
+    } else {
+        value
+    }
+}
+
+#[cfg(feature = "axum")]
+mod axum_export {
+    use std::convert::Infallible;
+
+    use axum::{
+        body::Body,
+        http::{header, HeaderValue},
+        response::{IntoResponse, Response},
+    };
+
+    use super::TableExport;
+
+    /// Streams the export as a download, encoding one row at a time.
+    impl<Row, Rows> IntoResponse for TableExport<Row, Rows>
+    where
+        Row: Send + 'static,
+        Rows: Iterator<Item = Row> + Send + 'static,
+    {
+        fn into_response(self) -> Response {
+            let content_type = HeaderValue::from_static(self.format.content_type());
+            let disposition =
+                HeaderValue::from_str(&format!("attachment; filename=\"{}\"", self.filename()))
+                    .unwrap_or_else(|_| HeaderValue::from_static("attachment"));
+
+            let body = Body::from_stream(futures_util::stream::iter(self.map(Ok::<_, Infallible>)));
+
+            (
+                [
+                    (header::CONTENT_TYPE, content_type),
+                    (header::CONTENT_DISPOSITION, disposition),
+                ],
+                body,
+            )
+                .into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExportFormat, Table, TableColumn, TableQuery};
+
+    #[test]
+    fn query_round_trip() {
+        let query = TableQuery::from_query_string("?sort=name&order=desc&search=jane+doe&page=3");
+
+        assert_eq!(query.sort.as_deref(), Some("name"));
+        assert!(query.descending);
+        assert_eq!(query.search.as_deref(), Some("jane doe"));
+        assert_eq!(query.offset(10), 20);
+        assert_eq!(
+            query.url("/users"),
+            "/users?sort=name&order=desc&search=jane%20doe&page=3"
+        );
+        assert_eq!(
+            query.sorted_by("name").url("/users"),
+            "/users?sort=name&order=asc&search=jane%20doe"
+        );
+        assert_eq!(TableQuery::from_query_string("page=abc").page, 1);
+    }
+
+    #[test]
+    fn render_table() {
+        let table = Table::new("people", "/people")
+            .with_column(TableColumn::new("name", "Name", |name: &&str| *name).sortable())
+            .with_column(TableColumn::markup("link", "Link", |name: &&str| {
+                format!("<a href=\"/people/{name}\">View</a>")
+            }))
+            .with_rows(["<script>", "Jane"])
+            .with_query(TableQuery::from_query_string("page=5"))
+            .with_pagination(200, 10)
+            .striped();
+
+        let rendered = table.to_string();
+
+        assert!(rendered.contains(r#"<table class="table table-striped">"#));
+        assert!(rendered.contains("&lt;script&gt;"));
+        assert!(rendered.contains(r#"<a href="/people/Jane">View</a>"#));
+        assert!(rendered.contains(r##"hx-target="#people-body""##));
+        assert_eq!(
+            table.pages(),
+            [
+                Some(1),
+                None,
+                Some(3),
+                Some(4),
+                Some(5),
+                Some(6),
+                Some(7),
+                None,
+                Some(20)
+            ]
+        );
+    }
+
+    #[test]
+    fn export_rows() {
+        let table = || {
+            Table::new("people", "/people")
+                .with_column(TableColumn::new("name", "Name", |name: &&str| *name))
+                .with_column(TableColumn::markup("badge", "Badge", |_: &&str| "<b>!</b>"))
+                .with_column(TableColumn::link(
+                    "profile",
+                    "Profile, link",
+                    |name: &&str| format!("/people/{name}"),
+                    |name: &&str| format!("\"{name}\""),
+                ))
+                .with_rows(["Jane", "John\nDoe"])
+                .with_query(TableQuery::from_query_string("search=j&page=2"))
+                .with_export(ExportFormat::Csv)
+        };
+
+        let buttons = table().export_buttons();
+        assert_eq!(buttons.len(), 1);
+        let button = buttons[0].to_string();
+        assert!(button.contains(r#"href="/people?search=j&amp;export=csv""#));
+        assert!(button.contains(r#"download="people.csv""#));
+
+        assert_eq!(
+            table().into_export(ExportFormat::Csv).collect::<String>(),
+            "Name,\"Profile, link\"\r\nJane,\"\"\"Jane\"\"\"\r\n\"John\nDoe\",\"\"\"John\nDoe\"\"\"\r\n"
+        );
+        assert_eq!(
+            table().into_export(ExportFormat::Json).collect::<String>(),
+            "[\n  {\"name\": \"Jane\", \"profile\": \"\\\"Jane\\\"\"},\n  {\"name\": \"John\\nDoe\", \"profile\": \"\\\"John\\nDoe\\\"\"}\n]\n"
+        );
+        assert_eq!(
+            Table::<&str>::new("empty", "/")
+                .into_export(ExportFormat::Json)
+                .collect::<String>(),
+            "[]\n"
+        );
+    }
+
+    #[test]
+    fn export_typed_values() {
+        let table = || {
+            Table::new("scores", "/scores")
+                .with_column(TableColumn::new("name", "Name", |row: &(&str, i32)| row.0))
+                .with_column(
+                    TableColumn::new("score", "Score", |row: &(&str, i32)| format!("{}pt", row.1))
+                        .with_export(|row: &(&str, i32)| row.1),
+                )
+                .with_column(
+                    TableColumn::new("passed", "Passed", |_: &(&str, i32)| "")
+                        .with_export(|row: &(&str, i32)| (row.1 != 0).then_some(row.1 > 5)),
+                )
+        };
+        let rows = || [("=HYPERLINK(\"x\")", 7), ("-2+3", -2), ("@sum", 0)];
+
+        assert_eq!(
+            table()
+                .into_export(ExportFormat::Csv)
+                .with_rows(rows())
+                .collect::<String>(),
+            "Name,Score,Passed\r\n\"'=HYPERLINK(\"\"x\"\")\",7,true\r\n'-2+3,-2,false\r\n'@sum,0,\r\n"
+        );
+        assert_eq!(
+            table()
+                .into_export(ExportFormat::Json)
+                .with_rows(rows().into_iter().skip(1))
+                .collect::<String>(),
+            "[\n  {\"name\": \"-2+3\", \"score\": -2, \"passed\": false},\n  {\"name\": \"@sum\", \"score\": 0, \"passed\": null}\n]\n"
+        );
+    }
+}
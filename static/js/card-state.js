@@ -0,0 +1,45 @@
+/*!
+ * Persists which collapsible cards the user collapsed or expanded in cookies,
+ * so they can be restored server-side. See `CardState` for the format.
+ */
+(function ($) {
+    "use strict";
+
+    var COLLAPSED_COOKIE = "cardsCollapsed";
+    var EXPANDED_COOKIE = "cardsExpanded";
+
+    function ids(cookie) {
+        var match = document.cookie.match(new RegExp("(?:^|; )" + cookie + "=([^;]*)"));
+        return match === null ? [] : match[1].split(".").filter(function (id) {
+            return id !== "";
+        });
+    }
+
+    function store(cookie, id, present) {
+        var stored = ids(cookie).filter(function (other) {
+            return other !== id;
+        });
+
+        if (present) {
+            stored.push(id);
+        }
+
+        document.cookie = cookie + "=" + stored.join(".") + "; path=/; max-age=31536000; SameSite=Lax";
+    }
+
+    // Clicking the buttons or context menu of a collapsible card header
+    // should not toggle the card.
+    $(document).on("click", ".card-header[data-toggle='collapse'] .card-header-actions", function (event) {
+        event.stopPropagation();
+    });
+
+    $(document).on("shown.bs.collapse hidden.bs.collapse", ".card > .collapse", function (event) {
+        if (event.target !== this) {
+            return;
+        }
+
+        var collapsed = event.type === "hidden";
+        store(COLLAPSED_COOKIE, this.id, collapsed);
+        store(EXPANDED_COOKIE, this.id, !collapsed);
+    });
+})(jQuery);
@@ -0,0 +1,172 @@
+/*!
+ * Draws the charts rendered by `Chart`, loading Chart.js from the same
+ * directory on first use, and refreshes those with a `data-chart-refresh` URL.
+ * The server-rendered SVG fallback is kept if Chart.js cannot be loaded.
+ */
+(function () {
+    "use strict";
+
+    var script = document.currentScript;
+    var chartJs = script.src.substring(0, script.src.lastIndexOf("/") + 1) + "Chart.min.js";
+    var pending = null;
+
+    function withChartJs(callback) {
+        if (window.Chart) {
+            callback();
+            return;
+        }
+
+        if (pending === null) {
+            pending = [];
+
+            var tag = document.createElement("script");
+            tag.src = chartJs;
+            tag.onload = function () {
+                pending.forEach(function (queued) {
+                    queued();
+                });
+            };
+            tag.onerror = function () {
+                console.warn("Failed to load " + chartJs + ", showing charts without Chart.js");
+            };
+            document.head.appendChild(tag);
+        }
+
+        pending.push(callback);
+    }
+
+    // Same output as `ValueFormat::format`.
+    function formatter(format) {
+        return function (value) {
+            var number = Number(value);
+            var text = format.decimals === null ? String(number) : number.toFixed(format.decimals);
+            var sign = text.charAt(0) === "-" ? "-" : "";
+            var parts = text.replace("-", "").split(".");
+            parts[0] = parts[0].replace(/\B(?=(\d{3})+(?!\d))/g, ",");
+
+            return format.prefix + sign + parts.join(".") + format.suffix;
+        };
+    }
+
+    function draw(canvas) {
+        var config = JSON.parse(canvas.getAttribute("data-chart"));
+        var format = formatter(config.format);
+        var circular = config.type === "pie" || config.type === "doughnut";
+        delete config.format;
+
+        var options = config.options;
+        options.maintainAspectRatio = false;
+        options.layout = { padding: { left: 10, right: 25, top: 25, bottom: 0 } };
+        options.tooltips = {
+            backgroundColor: "rgb(255, 255, 255)",
+            bodyFontColor: "#858796",
+            titleFontColor: "#6e707e",
+            titleMarginBottom: 10,
+            borderColor: "#dddfeb",
+            borderWidth: 1,
+            xPadding: 15,
+            yPadding: 15,
+            displayColors: false,
+            caretPadding: 10,
+            callbacks: {
+                label: function (item, data) {
+                    var dataset = data.datasets[item.datasetIndex];
+                    var label = circular ? data.labels[item.index] : dataset.label;
+
+                    return (label ? label + ": " : "") + format(dataset.data[item.index]);
+                }
+            }
+        };
+
+        if (!circular) {
+            var values = {
+                ticks: { maxTicksLimit: 5, padding: 10, beginAtZero: true, callback: format },
+                gridLines: {
+                    color: "rgb(234, 236, 244)",
+                    zeroLineColor: "rgb(234, 236, 244)",
+                    drawBorder: false,
+                    borderDash: [2],
+                    zeroLineBorderDash: [2]
+                }
+            };
+            var categories = {
+                gridLines: { display: false, drawBorder: false },
+                ticks: { maxTicksLimit: 7 }
+            };
+            var horizontal = config.type === "horizontalBar";
+
+            options.scales = {
+                xAxes: [horizontal ? values : categories],
+                yAxes: [horizontal ? categories : values]
+            };
+        }
+
+        var fallback = canvas.parentNode.querySelector(".chart-fallback");
+        if (fallback !== null) {
+            fallback.parentNode.removeChild(fallback);
+        }
+        canvas.hidden = false;
+        canvas.chart = new window.Chart(canvas, config);
+
+        var url = canvas.getAttribute("data-chart-refresh");
+        if (url === null) {
+            return;
+        }
+
+        var timer = setInterval(function () {
+            if (!document.body.contains(canvas)) {
+                clearInterval(timer);
+                return;
+            }
+
+            fetch(url, { headers: { Accept: "application/json" } })
+                .then(function (response) {
+                    if (!response.ok) {
+                        throw new Error(response.status + " " + response.statusText);
+                    }
+                    return response.json();
+                })
+                .then(function (data) {
+                    canvas.chart.data.labels = data.labels;
+                    data.datasets.forEach(function (dataset, index) {
+                        if (canvas.chart.data.datasets[index]) {
+                            canvas.chart.data.datasets[index].data = dataset.data;
+                        }
+                    });
+                    canvas.chart.update();
+                })
+                .catch(function (error) {
+                    console.warn("Failed to refresh chart " + canvas.id + ": " + error.message);
+                });
+        }, Number(canvas.getAttribute("data-chart-interval")));
+    }
+
+    function drawAll(root) {
+        var canvases = Array.prototype.slice.call(root.querySelectorAll("canvas[data-chart]"));
+        if (root.matches && root.matches("canvas[data-chart]")) {
+            canvases.push(root);
+        }
+
+        canvases = canvases.filter(function (canvas) {
+            return canvas.chart === undefined;
+        });
+
+        if (canvases.length > 0) {
+            withChartJs(function () {
+                canvases.forEach(function (canvas) {
+                    if (canvas.chart === undefined) {
+                        draw(canvas);
+                    }
+                });
+            });
+        }
+    }
+
+    document.addEventListener("DOMContentLoaded", function () {
+        drawAll(document);
+    });
+
+    document.addEventListener("htmx:load", function (event) {
+        drawAll(event.detail.elt);
+    });
+})();
@@ -0,0 +1,44 @@
+/*!
+ * Shows the loading spinner while lazily loaded card bodies are (re-)fetched,
+ * and an error message if they fail to load. See `Card::lazy`.
+ */
+(function () {
+    "use strict";
+
+    function lazyBody(event) {
+        var target = event.detail.target;
+        return target && target.hasAttribute("data-lazy-body") ? target : null;
+    }
+
+    document.addEventListener("htmx:beforeRequest", function (event) {
+        var body = lazyBody(event);
+        if (body === null) {
+            return;
+        }
+
+        if (body.lazyPlaceholder === undefined) {
+            body.lazyPlaceholder = body.innerHTML;
+        } else {
+            body.innerHTML = body.lazyPlaceholder;
+        }
+    });
+
+    function showError(event) {
+        var body = lazyBody(event);
+        if (body === null) {
+            return;
+        }
+
+        var status = event.detail.xhr ? event.detail.xhr.status : 0;
+
+        var error = document.createElement("div");
+        error.className = "text-danger text-center py-4";
+        error.textContent = body.getAttribute("data-error-message")
+            + (status > 0 ? " (" + status + " " + event.detail.xhr.statusText + ")" : "");
+
+        body.replaceChildren(error);
+    }
+
+    document.addEventListener("htmx:responseError", showError);
+    document.addEventListener("htmx:sendError", showError);
+})();
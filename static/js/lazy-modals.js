@@ -0,0 +1,45 @@
+/*!
+ * Resets the shared container of lazily loaded modals when it is closed, and
+ * shows an error if the modal fails to load. See `LinkAction::LazyModal`.
+ */
+(function ($) {
+    "use strict";
+
+    var container = document.getElementById("lazyModal");
+    if (container === null) {
+        return;
+    }
+
+    var loading = container.querySelector(".modal-dialog").outerHTML;
+
+    $(container).on("hidden.bs.modal", function () {
+        container.querySelector(".modal-dialog").outerHTML = loading;
+    });
+
+    function showError(event) {
+        // Only handle failures to load the modal, not requests made from within it.
+        if (container.contains(event.detail.elt) || !container.contains(event.detail.target)) {
+            return;
+        }
+
+        var status = event.detail.xhr ? event.detail.xhr.status : 0;
+
+        var header = document.createElement("div");
+        header.className = "modal-header";
+        header.innerHTML = '<h5 class="modal-title">Error</h5>'
+            + '<button class="close" type="button" data-dismiss="modal" aria-label="Close">'
+            + '<span aria-hidden="true">×</span></button>';
+
+        var body = document.createElement("div");
+        body.className = "modal-body text-danger";
+        body.textContent = status > 0
+            ? "Failed to load (" + status + " " + event.detail.xhr.statusText + ")."
+            : "Failed to load, please check your connection.";
+
+        var content = container.querySelector(".modal-content");
+        content.replaceChildren(header, body);
+    }
+
+    document.addEventListener("htmx:responseError", showError);
+    document.addEventListener("htmx:sendError", showError);
+})(jQuery);
@@ -0,0 +1,42 @@
+/*!
+ * Submits links carrying a `data-method` attribute as forms, so they can be
+ * used for logging out or deleting resources. See `LinkAction::Submit`.
+ */
+(function () {
+    "use strict";
+
+    function addField(form, name, value) {
+        var input = document.createElement("input");
+        input.type = "hidden";
+        input.name = name;
+        input.value = value;
+        form.appendChild(input);
+    }
+
+    document.addEventListener("click", function (event) {
+        var link = event.target.closest("a[data-method]");
+        if (link === null) {
+            return;
+        }
+
+        event.preventDefault();
+
+        var form = document.createElement("form");
+        form.method = "POST";
+        form.action = link.getAttribute("href");
+        form.style.display = "none";
+
+        var method = link.getAttribute("data-method").toUpperCase();
+        if (method !== "POST") {
+            addField(form, "_method", method);
+        }
+
+        var token = link.getAttribute("data-csrf-token");
+        if (token !== null) {
+            addField(form, "csrf_token", token);
+        }
+
+        document.body.appendChild(form);
+        form.submit();
+    });
+})();
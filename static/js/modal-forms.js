@@ -0,0 +1,20 @@
+/*!
+ * Closes modals containing htmx-submitted forms once the server signals
+ * success with a `204 No Content` response. See `Modal::form`.
+ */
+(function ($) {
+    "use strict";
+
+    document.addEventListener("htmx:afterRequest", function (event) {
+        var form = event.detail.elt;
+        if (!(form instanceof HTMLFormElement) || event.detail.xhr.status !== 204) {
+            return;
+        }
+
+        var modal = form.closest(".modal");
+        if (modal !== null) {
+            form.reset();
+            $(modal).modal("hide");
+        }
+    });
+})(jQuery);
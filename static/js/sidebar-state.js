@@ -0,0 +1,32 @@
+/*!
+ * Persists the SB-Admin-2 sidebar state (toggled & expanded menus) in cookies,
+ * so it can be restored server-side. See `SidebarState` for the format.
+ */
+(function ($) {
+    "use strict";
+
+    function setCookie(name, value) {
+        document.cookie = name + "=" + value + "; path=/; max-age=31536000; SameSite=Lax";
+    }
+
+    // The server renders the sidebar as toggled, but the body class used
+    // by the stylesheet is only ever set by the toggle buttons.
+    $(function () {
+        if ($(".sidebar").hasClass("toggled")) {
+            $("body").addClass("sidebar-toggled");
+        }
+    });
+
+    // Delegated, so it runs after SB-Admin-2's own toggle handler.
+    $(document).on("click", "#sidebarToggle, #sidebarToggleTop", function () {
+        setCookie("sidebarToggled", $(".sidebar").hasClass("toggled"));
+    });
+
+    $(document).on("shown.bs.collapse hidden.bs.collapse", "#accordionSidebar .collapse", function () {
+        var expanded = $("#accordionSidebar .collapse.show").map(function () {
+            return this.id;
+        }).get();
+
+        setCookie("sidebarExpanded", expanded.join("."));
+    });
+})(jQuery);